@@ -0,0 +1,125 @@
+use std::fmt::Display;
+
+use aoc_utils::graph;
+
+use crate::{
+    grid::Grid,
+    pipe_map::{Coord, PipeMap},
+};
+
+/// Breadth-first step counts from `PipeMap::start` to every tile reachable through
+/// connected pipes. Tiles that can't be reached from the start are `None`.
+pub struct DistanceMap(Grid<Option<u32>>);
+impl DistanceMap {
+    pub fn parse(pipe_map: &PipeMap) -> Self {
+        let mut distances = Grid::new(pipe_map.width(), pipe_map.height(), None);
+        if !pipe_map.is_in_bound(&pipe_map.start) {
+            return Self(distances);
+        }
+
+        let reachable = graph::bfs(pipe_map.start, |c| pipe_map.get_connected_tiles(c));
+        for (coord, distance) in reachable {
+            distances[&coord] = Some(distance as u32);
+        }
+
+        return Self(distances);
+    }
+
+    pub fn grid(&self) -> &Grid<Option<u32>> {
+        return &self.0;
+    }
+
+    pub fn get_distance(&self, x: usize, y: usize) -> Option<u32> {
+        return match self.0.get(&Coord::new(x, y)) {
+            Some(d) => *d,
+            None => None,
+        };
+    }
+
+    pub fn get_max_distance(&self) -> Option<u32> {
+        return self.0.iter().filter_map(|(_, d)| *d).max();
+    }
+
+    /// Returns the greatest distance from the start alongside every tile at that distance.
+    pub fn get_farthest_tiles(&self) -> Option<(u32, Vec<Coord>)> {
+        let max_distance = self.get_max_distance()?;
+        return Some((max_distance, self.get_tiles_at_distance(max_distance)));
+    }
+
+    pub fn get_tiles_at_distance(&self, k: u32) -> Vec<Coord> {
+        return self
+            .0
+            .iter()
+            .filter(|(_, d)| **d == Some(k))
+            .map(|(c, _)| c)
+            .collect();
+    }
+}
+impl Display for DistanceMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Only the last digit of each distance fits in a single tile.
+        let mut result = "".to_string();
+        for y in 0..self.0.height() {
+            for x in 0..self.0.width() {
+                let c = match self.0[&Coord::new(x, y)] {
+                    Some(d) => char::from_digit(d % 10, 10).unwrap(),
+                    None => '.',
+                };
+                result.push(c);
+            }
+            result.push('\n');
+        }
+        write!(f, "{}", result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::distance_map::DistanceMap;
+    use crate::pipe_map::{Coord, PipeMap};
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::path::Path;
+
+    pub fn open_file<P>(path: P) -> File
+    where
+        P: AsRef<Path> + std::fmt::Display,
+    {
+        let file = std::fs::File::open(&path);
+        match file {
+            Ok(file) => file,
+            Err(e) => panic!("Could not open file {}: {}", path, e),
+        }
+    }
+
+    fn parse_distance_map(file_path: &str) -> DistanceMap {
+        let buf = BufReader::new(open_file(file_path));
+        let pipe_map = PipeMap::parse(buf).unwrap();
+        return DistanceMap::parse(&pipe_map);
+    }
+
+    #[test]
+    fn test_distance_map_farthest() {
+        let distance_map = parse_distance_map("./test_input.txt");
+        let (distance, tiles) = distance_map.get_farthest_tiles().unwrap();
+        assert_eq!(distance, 8);
+        assert!(tiles == vec![Coord::new(4, 2)]);
+        assert_eq!(distance_map.get_distance(0, 2), Some(0));
+        assert_eq!(distance_map.get_distance(0, 0), None);
+        assert_eq!(distance_map.get_distance(99, 99), None);
+    }
+
+    #[test]
+    fn test_distance_map_branching() {
+        let distance_map = parse_distance_map("./test_input_branching.txt");
+        let (distance, tiles) = distance_map.get_farthest_tiles().unwrap();
+        assert_eq!(distance, 4);
+        assert!(tiles == vec![Coord::new(2, 0)]);
+        assert_eq!(distance_map.get_distance(1, 4), Some(3));
+        assert_eq!(distance_map.get_distance(2, 4), None);
+        assert!(
+            distance_map.get_tiles_at_distance(3)
+                == vec![Coord::new(1, 0), Coord::new(2, 1), Coord::new(1, 4)]
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use std::ops::{Index, IndexMut};
+
+use crate::pipe_map::Coord;
+
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        return Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        };
+    }
+}
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn is_in_bound(&self, pos: &Coord) -> bool {
+        return pos.x() < self.width && pos.y() < self.height;
+    }
+
+    pub fn get(&self, pos: &Coord) -> Option<&T> {
+        if !self.is_in_bound(pos) {
+            return None;
+        }
+        return Some(&self.cells[self.get_index(pos)]);
+    }
+
+    pub fn get_mut(&mut self, pos: &Coord) -> Option<&mut T> {
+        if !self.is_in_bound(pos) {
+            return None;
+        }
+        let i = self.get_index(pos);
+        return Some(&mut self.cells[i]);
+    }
+
+    /// Iterates every cell in row-major order alongside its coordinate.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        let width = self.width;
+        return self
+            .cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (Coord::new(i % width, i / width), cell));
+    }
+
+    fn get_index(&self, pos: &Coord) -> usize {
+        if !self.is_in_bound(pos) {
+            panic!(
+                "{} is out of bounds of Grid ({} x {})",
+                pos, self.width, self.height
+            );
+        }
+        return pos.y() * self.width + pos.x();
+    }
+}
+impl<T> Index<&Coord> for Grid<T> {
+    type Output = T;
+    fn index(&self, index: &Coord) -> &Self::Output {
+        return &self.cells[self.get_index(index)];
+    }
+}
+impl<T> IndexMut<&Coord> for Grid<T> {
+    fn index_mut(&mut self, index: &Coord) -> &mut Self::Output {
+        let i = self.get_index(index);
+        return &mut self.cells[i];
+    }
+}
@@ -1,2 +1,3 @@
+pub mod distance_map;
+pub mod grid;
 pub mod pipe_map;
-pub mod tile_crawler;
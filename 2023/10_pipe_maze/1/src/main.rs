@@ -3,7 +3,7 @@ use std::fs::File;
 use std::io::BufReader;
 use std::path::Path;
 
-use pipe_maze::{pipe_map::PipeMap, tile_crawler::TileCrawler};
+use pipe_maze::{distance_map::DistanceMap, pipe_map::PipeMap};
 
 const TESTFILEPATH: &str = "./test_input.txt";
 const FILEPATH: &str = "./input.txt";
@@ -23,8 +23,21 @@ fn main() {
         println!("Parsed PipeMap:\n{}", pipe_map);
     }
 
-    let crawler = TileCrawler::new(&pipe_map.start);
-    let result = crawler.get_longest_crawl_path(&pipe_map, debug_print);
+    let distance_map = DistanceMap::parse(&pipe_map);
+    if debug_print {
+        println!("Distances from start:\n{}", distance_map);
+    }
+    let result = match distance_map.get_farthest_tiles() {
+        Some((distance, tiles)) => {
+            if debug_print {
+                for tile in tiles.iter() {
+                    println!("Farthest tile: {}", tile);
+                }
+            }
+            distance
+        }
+        None => 0,
+    };
 
     println!("{}", result);
 }
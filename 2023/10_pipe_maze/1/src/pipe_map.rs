@@ -5,6 +5,7 @@ use std::{
     ops::Index,
 };
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Coord {
     x: usize,
     y: usize,
@@ -22,12 +23,6 @@ impl Coord {
         return self.y;
     }
 }
-impl Eq for Coord {}
-impl PartialEq for Coord {
-    fn eq(&self, other: &Self) -> bool {
-        return self.x() == other.x() && self.y() == other.y();
-    }
-}
 impl Display for Coord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "({}, {})", self.x(), self.y())
@@ -133,16 +128,42 @@ impl PipeMap {
     }
 
     pub fn is_connected_tile(&self, source: &Coord, dest: &Coord) -> bool {
-        if !self.is_in_bound(&source) || !self.is_in_bound(&dest) {
+        if !self.is_in_bound(source) || !self.is_in_bound(dest) {
             return false;
         }
 
-        return self[source].is_connected(&self[dest], &source, &dest);
+        return self[source].is_connected(&self[dest], source, dest);
     }
 
     pub fn is_in_bound(&self, pos: &Coord) -> bool {
         return pos.y() < self.map.len() && pos.x() < self.map[0].len();
     }
+
+    pub fn get_connected_tiles(&self, pos: &Coord) -> Vec<Coord> {
+        let mut result = vec![];
+        for (x_off, y_off) in [(-1, 0), (0, -1), (1, 0), (0, 1)] {
+            let x = pos.x().checked_add_signed(x_off);
+            let y = pos.y().checked_add_signed(y_off);
+            if let (Some(x), Some(y)) = (x, y) {
+                let next = Coord::new(x, y);
+                if self.is_connected_tile(pos, &next) {
+                    result.push(next);
+                }
+            }
+        }
+        return result;
+    }
+
+    pub fn width(&self) -> usize {
+        return match self.map.first() {
+            Some(row) => row.len(),
+            None => 0,
+        };
+    }
+
+    pub fn height(&self) -> usize {
+        return self.map.len();
+    }
 }
 impl Index<&Coord> for PipeMap {
     type Output = Tile;
@@ -189,11 +210,12 @@ mod tests {
         let buf = BufReader::new(open_file(file_path));
         let pipe_map = PipeMap::parse(buf).unwrap();
         let buf = BufReader::new(open_file(file_path));
-        let mut cmp = "Starting Coord: (2, 0)\n".to_string();
+        // Coords print as (x, y), and S is in column 0 of row 2.
+        let mut cmp = "Starting Coord: (0, 2)\n".to_string();
         for l in buf.lines() {
             let l = match l {
                 Ok(l) => l,
-                Err(e) => panic!("There was a problem parsing line: {}", e.to_string()),
+                Err(e) => panic!("There was a problem parsing line: {}", e),
             };
             cmp = format!("{}{}\n", cmp, l);
         }
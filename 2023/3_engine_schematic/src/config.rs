@@ -0,0 +1,184 @@
+use std::io::BufRead;
+
+/// Which cells around a digit count as touching it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Adjacency {
+    /// Only the cells above, below, left and right.
+    Four,
+    /// Diagonals as well.
+    Eight,
+}
+impl Adjacency {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        return match s {
+            "4" => Ok(Adjacency::Four),
+            "8" => Ok(Adjacency::Eight),
+            _ => Err(format!("Unknown adjacency {}, expected 4 or 8.", s)),
+        };
+    }
+
+    pub fn has_diagonals(&self) -> bool {
+        return *self == Adjacency::Eight;
+    }
+}
+
+/// How a schematic is read: which characters are symbols, which symbols can be gears,
+/// which cells touch a number, and how many part numbers a gear has to touch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchematicConfig {
+    symbols: Option<Vec<char>>,
+    gears: Vec<char>,
+    adjacency: Adjacency,
+    gear_neighbours: usize,
+}
+impl SchematicConfig {
+    /// With no `symbols`, every character other than a digit or a period is a symbol;
+    /// otherwise only those listed are, and everything else that isn't a digit is blank.
+    /// A gear is one of the `gears` symbols touching exactly `gear_neighbours` numbers.
+    pub fn new(
+        symbols: Option<&str>,
+        gears: &str,
+        adjacency: Adjacency,
+        gear_neighbours: usize,
+    ) -> Result<Self, String> {
+        let symbols = symbols.map(|s| s.chars().collect::<Vec<char>>());
+        let gears = gears.chars().collect::<Vec<char>>();
+
+        if let Some(symbols) = &symbols {
+            if symbols.is_empty() {
+                return Err("The symbol alphabet is empty.".to_string());
+            }
+            if let Some(c) = symbols.iter().find(|c| c.is_ascii_digit()) {
+                return Err(format!("Digit {} can't be a symbol.", c));
+            }
+        }
+        if gears.is_empty() {
+            return Err("There are no gear symbols.".to_string());
+        }
+        let config = Self {
+            symbols,
+            gears,
+            adjacency,
+            gear_neighbours,
+        };
+        if let Some(c) = config.gears.iter().find(|&&c| !config.is_symbol(c)) {
+            return Err(format!("Gear {} isn't a symbol.", c));
+        }
+        if gear_neighbours == 0 {
+            return Err("Gears have to touch at least one number.".to_string());
+        }
+
+        return Ok(config);
+    }
+
+    /// Reads a config from `key = value` lines, where every key is optional and falls back
+    /// to its default. Blank lines and lines starting with `#` are skipped, for example:
+    ///
+    /// ```text
+    /// symbols = *#+$
+    /// gears = *#
+    /// adjacency = 4
+    /// gear_neighbours = 3
+    /// ```
+    pub fn parse<R: BufRead>(buf: R) -> Result<Self, String> {
+        let mut symbols = None;
+        let mut gears = "*".to_string();
+        let mut adjacency = Adjacency::Eight;
+        let mut gear_neighbours = 2;
+
+        for l in buf.lines() {
+            let l = match l {
+                Ok(line) => line,
+                Err(e) => return Err(e.to_string()),
+            };
+            let l = l.trim();
+            if l.is_empty() || l.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = match l.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => return Err(format!("Config line is missing an '=': {}", l)),
+            };
+            match key {
+                "symbols" => symbols = Some(value.to_string()),
+                "gears" => gears = value.to_string(),
+                "adjacency" => adjacency = Adjacency::parse(value)?,
+                "gear_neighbours" => match value.parse::<usize>() {
+                    Ok(n) => gear_neighbours = n,
+                    Err(e) => return Err(format!("Invalid gear_neighbours {}: {}", value, e)),
+                },
+                _ => return Err(format!("Unknown config key: {}", key)),
+            }
+        }
+
+        return Self::new(symbols.as_deref(), &gears, adjacency, gear_neighbours);
+    }
+
+    pub fn is_symbol(&self, c: char) -> bool {
+        return match &self.symbols {
+            Some(symbols) => symbols.contains(&c),
+            None => !c.is_ascii_digit() && c != '.',
+        };
+    }
+
+    pub fn is_gear(&self, c: char) -> bool {
+        return self.gears.contains(&c);
+    }
+
+    pub fn adjacency(&self) -> Adjacency {
+        return self.adjacency;
+    }
+
+    pub fn gear_neighbours(&self) -> usize {
+        return self.gear_neighbours;
+    }
+}
+impl Default for SchematicConfig {
+    /// The puzzle's rules: anything but digits and periods is a symbol, diagonals touch,
+    /// and gears are `*` touching exactly two numbers.
+    fn default() -> Self {
+        return Self {
+            symbols: None,
+            gears: vec!['*'],
+            adjacency: Adjacency::Eight,
+            gear_neighbours: 2,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{Adjacency, SchematicConfig};
+
+    #[test]
+    fn test_config() {
+        let config = SchematicConfig::default();
+        assert!(config.is_symbol('@') && !config.is_symbol('.') && !config.is_symbol('7'));
+        assert_eq!(
+            SchematicConfig::parse("".as_bytes()),
+            Ok(SchematicConfig::default())
+        );
+
+        let text = "# Only two symbols count.\nsymbols = *#\ngears = *#\n\nadjacency = 4\n\
+                    gear_neighbours = 3\n";
+        let config = SchematicConfig::parse(text.as_bytes()).unwrap();
+        assert!(config.is_symbol('#') && !config.is_symbol('+'));
+        assert!(config.is_gear('#') && !config.is_gear('$'));
+        assert_eq!(config.adjacency(), Adjacency::Four);
+        assert_eq!(config.gear_neighbours(), 3);
+
+        for bad in [
+            "symbols =\n",
+            "symbols = *1\n",
+            "gears =\n",
+            "symbols = #\ngears = *\n",
+            "adjacency = 6\n",
+            "gear_neighbours = 0\n",
+            "colour = red\n",
+            "gears\n",
+        ] {
+            assert!(SchematicConfig::parse(bad.as_bytes()).is_err(), "{}", bad);
+        }
+    }
+}
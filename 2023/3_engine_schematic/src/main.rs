@@ -0,0 +1,74 @@
+use std::env;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use engine_schematic::{config::SchematicConfig, schematic::Schematic};
+
+const TESTFILEPATH: &str = "./test_input.txt";
+const FILEPATH: &str = "./input.txt";
+
+fn main() {
+    let args = env::args().collect::<Vec<String>>();
+    let part = match get_arg_value(&args, "--part") {
+        Some(p) => p.parse::<u8>().unwrap(),
+        None => 1,
+    };
+    let is_test = args.contains(&"-t".to_string());
+    let file_path = match is_test {
+        true => TESTFILEPATH,
+        false => FILEPATH,
+    };
+    let debug_print = args.contains(&"-d".to_string());
+
+    // --config reads which characters are symbols and gears, the adjacency and how many
+    // numbers a gear touches from a file, otherwise the puzzle's rules apply.
+    let config = match get_arg_value(&args, "--config") {
+        Some(path) => SchematicConfig::parse(BufReader::new(open_file(path))).unwrap(),
+        None => SchematicConfig::default(),
+    };
+
+    let buf = BufReader::new(open_file(file_path));
+    let schematic = Schematic::parse(buf, &config, debug_print).unwrap();
+
+    // --adjacent-to <symbol> lists the numbers touching that symbol, and
+    // --touching-more-than <n> lists the symbols touching more than n numbers.
+    if let Some(symbol) = get_arg_value(&args, "--adjacent-to") {
+        let symbol = symbol.chars().next().unwrap();
+        for number in schematic.get_numbers_adjacent_to(symbol) {
+            println!("{}", number);
+        }
+        return;
+    }
+    if let Some(count) = get_arg_value(&args, "--touching-more-than") {
+        let count = count.parse::<usize>().unwrap();
+        for (symbol, numbers) in schematic.get_symbols_touching_more_than(count) {
+            let values = numbers.iter().map(|n| n.value).collect::<Vec<u64>>();
+            println!("{} touches {:?}", symbol, values);
+        }
+        return;
+    }
+
+    let result = match part {
+        1 => schematic.calc_part_sum(debug_print).unwrap(),
+        2 => schematic.calc_gear_ratio_sum(debug_print).unwrap(),
+        _ => panic!("Unknown part {}, expected 1 or 2.", part),
+    };
+    println!("{}", result);
+}
+
+fn get_arg_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    let i = args.iter().position(|a| a == flag)?;
+    return args.get(i + 1).map(|a| a.as_str());
+}
+
+fn open_file<P>(path: P) -> File
+where
+    P: AsRef<Path> + std::fmt::Display,
+{
+    let file = std::fs::File::open(&path);
+    match file {
+        Ok(file) => file,
+        Err(e) => panic!("Could not open file {}: {}", path, e),
+    }
+}
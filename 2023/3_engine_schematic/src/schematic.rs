@@ -0,0 +1,355 @@
+use std::{collections::BTreeMap, fmt::Display, io::BufRead};
+
+use aoc_utils::graph;
+
+use crate::config::SchematicConfig;
+
+/// A character the config counts as a symbol, at its row and column, counting from 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Symbol {
+    pub row: usize,
+    pub col: usize,
+    pub value: char,
+}
+impl Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' at [{}, {}]", self.value, self.row, self.col)
+    }
+}
+
+/// A number in the schematic, spanning columns `start` to `end` inclusive of its row, with
+/// every symbol touching any of its digits.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PartNumber {
+    pub value: u64,
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+    pub symbols: Vec<Symbol>,
+}
+impl PartNumber {
+    /// Only numbers next to a symbol are part numbers.
+    pub fn is_part(&self) -> bool {
+        return !self.symbols.is_empty();
+    }
+}
+impl Display for PartNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbols = self
+            .symbols
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<String>>();
+        write!(
+            f,
+            "{} at row {}, columns {}-{}, touching [{}]",
+            self.value,
+            self.row,
+            self.start,
+            self.end,
+            symbols.join(", ")
+        )
+    }
+}
+
+/// Every number and symbol in a schematic, found in a single scan, along with which numbers
+/// each symbol touches.
+pub struct Schematic {
+    numbers: Vec<PartNumber>,
+    symbols: Vec<Symbol>,
+    config: SchematicConfig,
+    /// Indices into `numbers` of the numbers touching each symbol.
+    symbol_numbers: BTreeMap<Symbol, Vec<usize>>,
+}
+impl Schematic {
+    pub fn parse<R: BufRead>(
+        buf: R,
+        config: &SchematicConfig,
+        debug_print: bool,
+    ) -> Result<Self, String> {
+        let mut grid = Vec::<Vec<char>>::new();
+        for l in buf.lines() {
+            let l = match l {
+                Ok(l) => l,
+                Err(e) => return Err(e.to_string()),
+            };
+            if l.is_empty() {
+                continue;
+            }
+            grid.push(l.chars().collect());
+        }
+
+        let height = grid.len();
+        let width = grid.iter().map(|row| row.len()).max().unwrap_or_default();
+        let symbol_at = |row: usize, col: usize| match grid[row].get(col) {
+            Some(&c) if config.is_symbol(c) => Some(Symbol { row, col, value: c }),
+            _ => None,
+        };
+
+        let diagonals = config.adjacency().has_diagonals();
+        let mut numbers = Vec::<PartNumber>::new();
+        let mut symbols = Vec::<Symbol>::new();
+        for (row, line) in grid.iter().enumerate() {
+            let mut start = None;
+            for col in 0..=line.len() {
+                let is_digit = col < line.len() && line[col].is_ascii_digit();
+                match (is_digit, start) {
+                    (true, None) => start = Some(col),
+                    (false, Some(first)) => {
+                        start = None;
+                        let digits = line[first..col].iter().collect::<String>();
+                        let value = match digits.parse::<u64>() {
+                            Ok(n) => n,
+                            Err(e) => {
+                                return Err(format!(
+                                    "Number {} at [{}, {}] can't be read: {}",
+                                    digits, row, first, e
+                                ))
+                            }
+                        };
+
+                        let mut adjacent = (first..col)
+                            .flat_map(|c| graph::grid_neighbours(c, row, width, height, diagonals))
+                            .filter_map(|(c, r)| symbol_at(r, c))
+                            .collect::<Vec<Symbol>>();
+                        adjacent.sort();
+                        adjacent.dedup();
+
+                        numbers.push(PartNumber {
+                            value,
+                            row,
+                            start: first,
+                            end: col - 1,
+                            symbols: adjacent,
+                        });
+                    }
+                    _ => (),
+                }
+                if col < line.len() {
+                    symbols.extend(symbol_at(row, col));
+                }
+            }
+        }
+
+        let mut symbol_numbers = symbols
+            .iter()
+            .map(|&s| (s, vec![]))
+            .collect::<BTreeMap<Symbol, Vec<usize>>>();
+        for (i, number) in numbers.iter().enumerate() {
+            for symbol in number.symbols.iter() {
+                symbol_numbers.entry(*symbol).or_default().push(i);
+            }
+        }
+
+        if debug_print {
+            println!("Numbers:");
+            for number in numbers.iter() {
+                println!("{}", number);
+            }
+        }
+
+        return Ok(Self {
+            numbers,
+            symbols,
+            config: config.clone(),
+            symbol_numbers,
+        });
+    }
+
+    /// Every number in the schematic in reading order, whether or not it's a part number.
+    pub fn numbers(&self) -> &[PartNumber] {
+        return &self.numbers;
+    }
+
+    /// Every symbol in the schematic in reading order.
+    pub fn symbols(&self) -> &[Symbol] {
+        return &self.symbols;
+    }
+
+    /// The numbers touching `symbol`, in reading order.
+    pub fn get_adjacent_numbers(&self, symbol: &Symbol) -> Vec<&PartNumber> {
+        return match self.symbol_numbers.get(symbol) {
+            Some(indices) => indices.iter().map(|&i| &self.numbers[i]).collect(),
+            None => vec![],
+        };
+    }
+
+    /// The numbers touching at least one `symbol` character.
+    pub fn get_numbers_adjacent_to(&self, symbol: char) -> Vec<&PartNumber> {
+        return self
+            .numbers
+            .iter()
+            .filter(|n| n.symbols.iter().any(|s| s.value == symbol))
+            .collect();
+    }
+
+    /// The symbols touching more than `count` numbers, along with those numbers.
+    pub fn get_symbols_touching_more_than(&self, count: usize) -> Vec<(&Symbol, Vec<&PartNumber>)> {
+        return self
+            .symbols
+            .iter()
+            .map(|s| (s, self.get_adjacent_numbers(s)))
+            .filter(|(_, numbers)| numbers.len() > count)
+            .collect();
+    }
+
+    /// Gear symbols touching exactly as many part numbers as the config asks for, along
+    /// with those numbers.
+    pub fn get_gears(&self) -> Vec<(&Symbol, Vec<&PartNumber>)> {
+        return self
+            .symbols
+            .iter()
+            .filter(|s| self.config.is_gear(s.value))
+            .map(|s| (s, self.get_adjacent_numbers(s)))
+            .filter(|(_, numbers)| numbers.len() == self.config.gear_neighbours())
+            .collect();
+    }
+
+    /// Part 1: the sum of every part number.
+    pub fn calc_part_sum(&self, debug_print: bool) -> Result<u64, String> {
+        let mut result: u64 = 0;
+        for number in self.numbers.iter().filter(|n| n.is_part()) {
+            if debug_print {
+                println!("Part number {}", number);
+            }
+            result = match result.checked_add(number.value) {
+                Some(n) => n,
+                None => return Err("The sum of part numbers overflowed a u64.".to_string()),
+            };
+        }
+
+        return Ok(result);
+    }
+
+    /// Part 2: the sum of every gear's ratio, which is the product of the part numbers it
+    /// touches.
+    pub fn calc_gear_ratio_sum(&self, debug_print: bool) -> Result<u64, String> {
+        let overflow = || "The sum of gear ratios overflowed a u64.".to_string();
+        let mut result: u64 = 0;
+        for (gear, numbers) in self.get_gears() {
+            let values = numbers.iter().map(|n| n.value).collect::<Vec<u64>>();
+            if debug_print {
+                println!("Gear {} - Adjacent parts: {:?}", gear, values);
+            }
+            let mut ratio: u64 = 1;
+            for value in values {
+                ratio = ratio.checked_mul(value).ok_or_else(overflow)?;
+            }
+            result = result.checked_add(ratio).ok_or_else(overflow)?;
+        }
+
+        return Ok(result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{Adjacency, SchematicConfig};
+    use crate::schematic::{Schematic, Symbol};
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::path::Path;
+
+    pub fn open_file<P>(path: P) -> File
+    where
+        P: AsRef<Path> + std::fmt::Display,
+    {
+        let file = std::fs::File::open(&path);
+        match file {
+            Ok(file) => file,
+            Err(e) => panic!("Could not open file {}: {}", path, e),
+        }
+    }
+
+    fn values(numbers: &[&crate::schematic::PartNumber]) -> Vec<u64> {
+        return numbers.iter().map(|n| n.value).collect();
+    }
+
+    #[test]
+    fn test_parts() {
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        let schematic = Schematic::parse(buf, &SchematicConfig::default(), false).unwrap();
+        assert_eq!(schematic.calc_part_sum(false), Ok(4361));
+        assert_eq!(schematic.calc_gear_ratio_sum(false), Ok(467835));
+    }
+
+    #[test]
+    fn test_index() {
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        let schematic = Schematic::parse(buf, &SchematicConfig::default(), false).unwrap();
+        assert_eq!(schematic.numbers().len(), 10);
+        assert_eq!(schematic.symbols().len(), 6);
+
+        let first = &schematic.numbers()[0];
+        let star = Symbol {
+            row: 1,
+            col: 3,
+            value: '*',
+        };
+        assert_eq!(
+            (first.value, first.row, first.start, first.end),
+            (467, 0, 0, 2)
+        );
+        assert_eq!(first.symbols, vec![star]);
+        assert_eq!(
+            first.to_string(),
+            "467 at row 0, columns 0-2, touching ['*' at [1, 3]]"
+        );
+        assert!(!schematic.numbers()[1].is_part());
+
+        assert_eq!(
+            values(&schematic.get_adjacent_numbers(&star)),
+            vec![467, 35]
+        );
+        assert_eq!(values(&schematic.get_numbers_adjacent_to('#')), vec![633]);
+        assert_eq!(values(&schematic.get_numbers_adjacent_to('*')).len(), 5);
+        assert!(schematic.get_numbers_adjacent_to('@').is_empty());
+
+        let busy = schematic.get_symbols_touching_more_than(1);
+        let busy = busy
+            .iter()
+            .map(|(s, numbers)| (s.row, s.col, values(numbers)))
+            .collect::<Vec<_>>();
+        assert_eq!(busy, vec![(1, 3, vec![467, 35]), (8, 5, vec![755, 598])]);
+        assert_eq!(schematic.get_symbols_touching_more_than(0).len(), 6);
+    }
+
+    #[test]
+    fn test_config() {
+        let parse = |config: &SchematicConfig| {
+            let buf = BufReader::new(open_file("./test_input.txt"));
+            return Schematic::parse(buf, config, false).unwrap();
+        };
+
+        // Without diagonals, 467, 592 and 755 no longer touch a symbol, which leaves each
+        // gear touching a single number.
+        let config = SchematicConfig::new(None, "*", Adjacency::Four, 2).unwrap();
+        let schematic = parse(&config);
+        assert_eq!(schematic.calc_part_sum(false), Ok(2547));
+        assert_eq!(schematic.calc_gear_ratio_sum(false), Ok(0));
+
+        // Only '*' and '#' are symbols, and gears are either one touching a single
+        // number.
+        let config = SchematicConfig::new(Some("*#"), "*#", Adjacency::Eight, 1).unwrap();
+        let schematic = parse(&config);
+        assert_eq!(schematic.symbols().len(), 4);
+        assert_eq!(schematic.calc_part_sum(false), Ok(3105));
+        assert_eq!(schematic.calc_gear_ratio_sum(false), Ok(617 + 633));
+    }
+
+    #[test]
+    fn test_edges() {
+        let config = SchematicConfig::default();
+        // Numbers at the ends of rows, rows of different lengths, and a symbol touching a
+        // number from below its last digit.
+        let schematic = Schematic::parse("12.\n..#5\n7\n".as_bytes(), &config, false).unwrap();
+        let numbers = schematic.numbers().iter().map(|n| (n.value, n.is_part()));
+        assert_eq!(
+            numbers.collect::<Vec<_>>(),
+            vec![(12, true), (5, true), (7, false)]
+        );
+
+        let schematic = Schematic::parse("99999999999999999999999\n".as_bytes(), &config, false);
+        assert!(schematic.is_err());
+    }
+}
@@ -0,0 +1,441 @@
+use std::{collections::HashSet, io::BufRead};
+
+/// How `CardVec::parse` handles cards that fail validation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Validation {
+    /// The first bad card fails the whole sheet.
+    Strict,
+    /// Bad cards are skipped and kept aside with the reason they were rejected.
+    Lenient,
+}
+
+/// A card that was left out of the sheet in lenient mode.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SkippedCard {
+    pub line: usize,
+    pub text: String,
+    pub error: String,
+}
+impl std::fmt::Display for SkippedCard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Line {}: {}", self.line, self.error)
+    }
+}
+
+pub struct CardVec {
+    cards: Vec<Card>,
+    skipped: Vec<SkippedCard>,
+}
+impl CardVec {
+    /// Reads one card per line, skipping blank lines. Besides each card being well formed,
+    /// card IDs have to count up by one from Card 1. A card whose ID could be read still
+    /// moves the count on when it's skipped for its numbers, so one bad card doesn't get
+    /// every card after it rejected too.
+    pub fn parse<R: BufRead>(
+        buf: R,
+        validation: Validation,
+        debug_print: bool,
+    ) -> Result<Self, String> {
+        let mut cards = Vec::<Card>::new();
+        let mut skipped = Vec::<SkippedCard>::new();
+        let mut seen = HashSet::<usize>::new();
+        let mut last_id = 0;
+
+        if debug_print {
+            println!("Cards:")
+        }
+        for (i, l) in buf.lines().enumerate() {
+            let l = match l {
+                Ok(l) => l,
+                Err(e) => return Err(format!("Couldn't read line {}: {}", i + 1, e)),
+            };
+            if l.trim().is_empty() {
+                continue;
+            }
+
+            let card = Card::parse_id(&l).and_then(|id| {
+                if !seen.insert(id) {
+                    return Err(format!("Card {} appears more than once.", id));
+                }
+                let expected = last_id + 1;
+                last_id = last_id.max(id);
+                if id != expected {
+                    return Err(format!("Expected Card {} but found Card {}.", expected, id));
+                }
+                return Card::parse(l.clone());
+            });
+
+            match (card, validation) {
+                (Ok(card), _) => {
+                    if debug_print {
+                        println!("{}", card);
+                    }
+                    cards.push(card);
+                }
+                (Err(e), Validation::Strict) => return Err(format!("Line {}: {}", i + 1, e)),
+                (Err(e), Validation::Lenient) => {
+                    let card = SkippedCard {
+                        line: i + 1,
+                        text: l,
+                        error: e,
+                    };
+                    if debug_print {
+                        println!("Skipped {}", card);
+                    }
+                    skipped.push(card);
+                }
+            }
+        }
+
+        return Ok(CardVec { cards, skipped });
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        return &self.cards;
+    }
+
+    /// The cards left out in lenient mode, in sheet order.
+    pub fn skipped(&self) -> &[SkippedCard] {
+        return &self.skipped;
+    }
+
+    /// Positions of the cards that the card at position `i` wins copies of. Its matches
+    /// win the cards with the IDs right after its own, but skipped cards can't be won.
+    pub fn get_wins(&self, i: usize) -> std::ops::Range<usize> {
+        let last_won = self.cards[i].id + self.cards[i].calc_score() as usize;
+        let end = self.cards.partition_point(|c| c.id <= last_won);
+        return i + 1..end.max(i + 1);
+    }
+
+    /// Part 1: the sum of every card's points, where a card is worth 1 point for its
+    /// first match and doubles for every match after that.
+    pub fn calc_points(&self, debug_print: bool) -> i32 {
+        let mut result = 0;
+
+        for card in self.cards.iter() {
+            let points = card.calc_points();
+            if debug_print {
+                println!(
+                    "Card {}: winning nums: {:?} | our nums {:?} = points: {}",
+                    card.id, card.winning_nums, card.our_nums, points
+                );
+            }
+            result += points;
+        }
+
+        return result;
+    }
+
+    /// Part 2: every match wins a copy of one of the following cards, so returns how many
+    /// cards are held once all the copies have been scored.
+    pub fn walk(&self, debug_print: bool) -> Result<u64, String> {
+        let counts = self.get_copy_counts(debug_print)?;
+        let mut result: u64 = 0;
+        for count in counts {
+            result = match result.checked_add(count) {
+                Some(n) => n,
+                None => return Err("The total number of cards overflowed a u64.".to_string()),
+            };
+        }
+
+        return Ok(result);
+    }
+
+    /// How many copies of each card are held once every card has been scored, in card
+    /// order. Each card adds its copies to the run of cards it wins, and rather than adding
+    /// them to every card in the run, they're added where the run starts and taken away
+    /// where it ends, so a running total gives each card's copies in a single pass.
+    pub fn get_copy_counts(&self, debug_print: bool) -> Result<Vec<u64>, String> {
+        let cards = self.cards.len();
+        let mut changes = vec![0i128; cards + 1];
+        let mut won: i128 = 0;
+        let mut result = Vec::with_capacity(cards);
+
+        for (i, card) in self.cards.iter().enumerate() {
+            won += changes[i];
+            let count = match u64::try_from(1 + won) {
+                Ok(n) => n,
+                Err(_) => {
+                    return Err(format!(
+                        "Card {} has more copies than fit in a u64.",
+                        card.id
+                    ))
+                }
+            };
+
+            let wins = self.get_wins(i);
+            if !wins.is_empty() {
+                changes[wins.start] += count as i128;
+                changes[wins.end] -= count as i128;
+            }
+            if debug_print {
+                println!("{}, Card Count: {}", card, count);
+            }
+            result.push(count);
+        }
+
+        return Ok(result);
+    }
+}
+
+/// A set of card numbers, one bit for each possible number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NumberSet([u128; 2]);
+impl NumberSet {
+    pub fn insert(&mut self, n: u8) {
+        self.0[n as usize / 128] |= 1 << (n % 128);
+    }
+
+    pub fn contains(&self, n: u8) -> bool {
+        return self.0[n as usize / 128] & (1 << (n % 128)) != 0;
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        return Self([self.0[0] & other.0[0], self.0[1] & other.0[1]]);
+    }
+
+    pub fn len(&self) -> u32 {
+        return self.0[0].count_ones() + self.0[1].count_ones();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+}
+impl FromIterator<u8> for NumberSet {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut result = Self::default();
+        for n in iter {
+            result.insert(n);
+        }
+        return result;
+    }
+}
+
+pub struct Card {
+    id: usize,
+    winning_nums: Vec<u8>,
+    our_nums: Vec<u8>,
+    matches: u32,
+}
+impl Card {
+    /// Reads just the ID from a card's line, so cards with bad numbers can still be
+    /// checked for their place in the sheet.
+    pub fn parse_id(line: &str) -> Result<usize, String> {
+        let card_trim_index = match line.find(':') {
+            Some(i) => i,
+            None => return Err(format!("Line contains no colon:\n\t{}", line)),
+        };
+        let id = line[0..card_trim_index].replace("Card", "");
+        return match id.trim().parse::<usize>() {
+            Ok(c_id) => Ok(c_id),
+            Err(e) => Err(format!("Card ID '{}' isn't a number: {}", id.trim(), e)),
+        };
+    }
+
+    pub fn parse(line: String) -> Result<Self, String> {
+        let id = Self::parse_id(&line)?;
+        let card_trim_index = line.find(':').unwrap_or_default();
+        let pipe_index = match line.find('|') {
+            Some(i) => i,
+            None => return Err(format!("Line contains no pipe:\n\t{}", line)),
+        };
+        if line[pipe_index + 1..].contains('|') {
+            return Err(format!("Line contains more than one pipe:\n\t{}", line));
+        }
+        let (winning_nums, our_nums) = (
+            Self::parse_nums(&line[card_trim_index + 1..pipe_index], "winning numbers")?,
+            Self::parse_nums(&line[pipe_index + 1..line.len()], "our numbers")?,
+        );
+
+        let winning_set = winning_nums.iter().copied().collect::<NumberSet>();
+        let our_set = our_nums.iter().copied().collect::<NumberSet>();
+        let matches = winning_set.intersection(&our_set).len();
+
+        return Ok(Self {
+            id,
+            winning_nums,
+            our_nums,
+            matches,
+        });
+    }
+
+    pub fn id(&self) -> usize {
+        return self.id;
+    }
+
+    /// How many of our numbers are winning numbers.
+    pub fn calc_score(&self) -> u32 {
+        return self.matches;
+    }
+
+    pub fn calc_points(&self) -> i32 {
+        let winning_count = self.calc_score();
+        if winning_count == 0 {
+            return 0;
+        }
+        return 2_i32.pow(winning_count - 1);
+    }
+
+    /// Reads one side of a card, which is made of numbers that fit in a `u8` separated by
+    /// whitespace, with no number listed twice.
+    fn parse_nums(s: &str, side: &str) -> Result<Vec<u8>, String> {
+        let mut result = Vec::new();
+        let mut seen = NumberSet::default();
+
+        for num_string in s.split_whitespace() {
+            if let Some(c) = num_string.chars().find(|c| !c.is_ascii_digit()) {
+                return Err(format!("Unexpected character '{}' in {}.", c, side));
+            }
+            let n = match num_string.parse::<u8>() {
+                Ok(n) => n,
+                Err(_) => {
+                    return Err(format!(
+                        "Number {} in {} is larger than {}.",
+                        num_string,
+                        side,
+                        u8::MAX
+                    ))
+                }
+            };
+            if seen.contains(n) {
+                return Err(format!("Number {} appears twice in {}.", n, side));
+            }
+            seen.insert(n);
+            result.push(n);
+        }
+
+        return Ok(result);
+    }
+}
+impl std::fmt::Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Card ID: {}, Card Score: {}", self.id, self.calc_score())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::{CardVec, SkippedCard, Validation};
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::path::Path;
+
+    pub fn open_file<P>(path: P) -> File
+    where
+        P: AsRef<Path> + std::fmt::Display,
+    {
+        let file = std::fs::File::open(&path);
+        match file {
+            Ok(file) => file,
+            Err(e) => panic!("Could not open file {}: {}", path, e),
+        }
+    }
+
+    #[test]
+    fn test_calc_points() {
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        let cards = CardVec::parse(buf, Validation::Strict, false).unwrap();
+        assert_eq!(cards.calc_points(false), 13);
+    }
+
+    #[test]
+    fn test_walk() {
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        let cards = CardVec::parse(buf, Validation::Strict, false).unwrap();
+        assert_eq!(cards.get_copy_counts(false), Ok(vec![1, 2, 4, 8, 14, 1]));
+        assert_eq!(cards.walk(false), Ok(30));
+    }
+
+    #[test]
+    fn test_validation() {
+        let sheet = "Card 1: 1 2 | 1 3\n\
+                     Card 2: 4 4 | 5\n\
+                     Card 3: 1 2 3 1 2\n\
+                     Card 3: 1 | 1\n\
+                     Card 5: 1 | 2\n\
+                     Card 6: 300 | 1\n\
+                     Card 7: 1 2 | 2 9\n";
+        let error = CardVec::parse(sheet.as_bytes(), Validation::Strict, false);
+        assert_eq!(
+            error.err(),
+            Some("Line 2: Number 4 appears twice in winning numbers.".to_string())
+        );
+
+        let cards = CardVec::parse(sheet.as_bytes(), Validation::Lenient, false).unwrap();
+        let ids = cards.cards().iter().map(|c| c.id()).collect::<Vec<usize>>();
+        assert_eq!(ids, vec![1, 7]);
+        let skipped = cards
+            .skipped()
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(
+            skipped,
+            vec![
+                "Line 2: Number 4 appears twice in winning numbers.",
+                "Line 3: Line contains no pipe:\n\tCard 3: 1 2 3 1 2",
+                "Line 4: Card 3 appears more than once.",
+                "Line 5: Expected Card 4 but found Card 5.",
+                "Line 6: Number 300 in winning numbers is larger than 255.",
+            ]
+        );
+        assert_eq!(
+            cards.skipped()[0],
+            SkippedCard {
+                line: 2,
+                text: "Card 2: 4 4 | 5".to_string(),
+                error: "Number 4 appears twice in winning numbers.".to_string()
+            }
+        );
+        // Card 1's match would win a copy of Card 2, but that was skipped.
+        assert_eq!(cards.get_copy_counts(false), Ok(vec![1, 1]));
+
+        let sheet = "Card 1: 1 2 | 1 2\nCard 2: 1 | x\nCard 3: 5 | 6\n";
+        let cards = CardVec::parse(sheet.as_bytes(), Validation::Lenient, false).unwrap();
+        assert_eq!(cards.get_copy_counts(false), Ok(vec![1, 2]));
+        assert_eq!(
+            cards.skipped()[0].error,
+            "Unexpected character 'x' in our numbers."
+        );
+        assert!(CardVec::parse("Card 2: 1 | 1\n".as_bytes(), Validation::Strict, false).is_err());
+        assert!(
+            CardVec::parse("Card 1: 1 | 1 | 1\n".as_bytes(), Validation::Strict, false).is_err()
+        );
+    }
+
+    /// Cards that each match `matches` numbers.
+    fn generate_cards(count: usize, matches: usize) -> CardVec {
+        let winning = (1..=5).map(|n| n.to_string()).collect::<Vec<String>>();
+        let ours = (1..=matches)
+            .map(|n| n.to_string())
+            .collect::<Vec<String>>();
+        let mut sheet = "".to_string();
+        for id in 1..=count {
+            sheet = sheet
+                + &format!(
+                    "Card {}: {} | {} 99 200\n",
+                    id,
+                    winning.join(" "),
+                    ours.join(" ")
+                );
+        }
+        return CardVec::parse(sheet.as_bytes(), Validation::Strict, false).unwrap();
+    }
+
+    #[test]
+    fn test_walk_many_cards() {
+        // Every card wins one copy of the next, so card n ends up with n copies.
+        let cards = generate_cards(200_000, 1);
+        let counts = cards.get_copy_counts(false).unwrap();
+        assert_eq!((counts[0], counts[199_999]), (1, 200_000));
+        assert_eq!(cards.walk(false), Ok(200_000 * 200_001 / 2));
+
+        // Winning the next two cards grows like the Fibonacci numbers, which soon overflow.
+        let counts = generate_cards(30, 2).get_copy_counts(false).unwrap();
+        for i in 2..29 {
+            assert_eq!(counts[i], 1 + counts[i - 1] + counts[i - 2]);
+        }
+        assert!(generate_cards(100, 2).walk(false).is_err());
+    }
+}
@@ -0,0 +1,207 @@
+use std::fmt::Display;
+
+use crate::card::CardVec;
+
+/// Where one card's copies came from.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Provenance {
+    pub card: usize,
+    pub copies: u64,
+    /// Every earlier card that won copies of this one, with how many it won, in card
+    /// order. The original card isn't included.
+    pub sources: Vec<(usize, u64)>,
+}
+impl Display for Provenance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Card {}: {} copies, 1 original", self.card, self.copies)?;
+        for (source, copies) in self.sources.iter() {
+            write!(f, ", {} from Card {}", copies, source)?;
+        }
+        return Ok(());
+    }
+}
+
+/// How copies of each card are won from the cards before it. Every copy of a card wins
+/// one copy of each of the cards right after it, one per match.
+pub struct Cascade<'a> {
+    cards: &'a CardVec,
+    counts: Vec<u64>,
+}
+impl<'a> Cascade<'a> {
+    pub fn new(cards: &'a CardVec) -> Result<Self, String> {
+        let counts = cards.get_copy_counts(false)?;
+        return Ok(Self { cards, counts });
+    }
+
+    fn get_position(&self, id: usize) -> Result<usize, String> {
+        return match self.cards.cards().iter().position(|c| c.id() == id) {
+            Some(i) => Ok(i),
+            None => Err(format!("There is no Card {}.", id)),
+        };
+    }
+
+    /// How many copies of card `id` each earlier card won.
+    pub fn get_provenance(&self, id: usize) -> Result<Provenance, String> {
+        let position = self.get_position(id)?;
+        let cards = self.cards.cards();
+        let sources = (0..position)
+            .filter(|&i| self.cards.get_wins(i).contains(&position))
+            .map(|i| (cards[i].id(), self.counts[i]))
+            .collect();
+
+        return Ok(Provenance {
+            card: id,
+            copies: self.counts[position],
+            sources,
+        });
+    }
+
+    /// Follows one copy of card `id` through every copy it wins, and every copy those win,
+    /// `max_depth` levels down, listing each card with the cards it wins:
+    ///
+    /// ```text
+    /// Card 3 wins 4, 5
+    ///   Card 4 wins 5
+    ///     Card 5
+    ///   Card 5
+    /// ```
+    pub fn to_tree(&self, id: usize, max_depth: usize) -> Result<String, String> {
+        let mut result = "".to_string();
+        self.write_tree(self.get_position(id)?, 0, max_depth, &mut result);
+        return Ok(result);
+    }
+
+    fn write_tree(&self, i: usize, depth: usize, max_depth: usize, result: &mut String) {
+        let cards = self.cards.cards();
+        let wins = self.cards.get_wins(i);
+        let won = wins
+            .clone()
+            .map(|w| cards[w].id().to_string())
+            .collect::<Vec<String>>();
+        let line = match won.is_empty() {
+            true => format!("Card {}", cards[i].id()),
+            false => format!("Card {} wins {}", cards[i].id(), won.join(", ")),
+        };
+        result.push_str(&format!("{}{}\n", "  ".repeat(depth), line));
+
+        if depth < max_depth {
+            for w in wins {
+                self.write_tree(w, depth + 1, max_depth, result);
+            }
+        }
+    }
+
+    /// Renders the cascade between cards `first` and `last` as a Graphviz digraph, with an
+    /// edge from each card to every card it wins, labelled with how many copies it wins.
+    pub fn to_dot(&self, first: usize, last: usize) -> Result<String, String> {
+        if first > last {
+            return Err(format!("Card range {} to {} is empty.", first, last));
+        }
+        // Card ids only go up, so the cards in range sit next to each other.
+        let cards = self.cards.cards();
+        let start = cards.partition_point(|c| c.id() < first);
+        let end = cards.partition_point(|c| c.id() <= last);
+        if start == end {
+            return Err(format!("There are no cards from {} to {}.", first, last));
+        }
+
+        let mut result = "digraph scratchcards {\n".to_string();
+        for (card, count) in cards[start..end].iter().zip(&self.counts[start..end]) {
+            result.push_str(&format!(
+                "\t\"Card {}\" [label=\"Card {}\\n{} copies\"];\n",
+                card.id(),
+                card.id(),
+                count
+            ));
+        }
+        for i in start..end {
+            for w in self.cards.get_wins(i).filter(|&w| w < end) {
+                result.push_str(&format!(
+                    "\t\"Card {}\" -> \"Card {}\" [label=\"{}\"];\n",
+                    cards[i].id(),
+                    cards[w].id(),
+                    self.counts[i]
+                ));
+            }
+        }
+        result.push_str("}\n");
+
+        return Ok(result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::{CardVec, Validation};
+    use crate::cascade::{Cascade, Provenance};
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::path::Path;
+
+    pub fn open_file<P>(path: P) -> File
+    where
+        P: AsRef<Path> + std::fmt::Display,
+    {
+        let file = std::fs::File::open(&path);
+        match file {
+            Ok(file) => file,
+            Err(e) => panic!("Could not open file {}: {}", path, e),
+        }
+    }
+
+    #[test]
+    fn test_provenance() {
+        let cards = CardVec::parse(
+            BufReader::new(open_file("./test_input.txt")),
+            Validation::Strict,
+            false,
+        )
+        .unwrap();
+        let cascade = Cascade::new(&cards).unwrap();
+
+        let provenance = cascade.get_provenance(5).unwrap();
+        assert_eq!(
+            provenance,
+            Provenance {
+                card: 5,
+                copies: 14,
+                sources: vec![(1, 1), (3, 4), (4, 8)]
+            }
+        );
+        assert_eq!(
+            provenance.to_string(),
+            "Card 5: 14 copies, 1 original, 1 from Card 1, 4 from Card 3, 8 from Card 4"
+        );
+        assert!(cascade.get_provenance(1).unwrap().sources.is_empty());
+        assert!(cascade.get_provenance(7).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let cards = CardVec::parse(
+            BufReader::new(open_file("./test_input.txt")),
+            Validation::Strict,
+            false,
+        )
+        .unwrap();
+        let cascade = Cascade::new(&cards).unwrap();
+
+        assert_eq!(
+            cascade.to_tree(2, 1).unwrap(),
+            "Card 2 wins 3, 4\n  Card 3 wins 4, 5\n  Card 4 wins 5\n"
+        );
+        // Every line below the first is one copy won on the way down from a single Card 2.
+        let tree = cascade.to_tree(2, 10).unwrap();
+        assert_eq!(tree.lines().count(), 7);
+        assert_eq!(cascade.to_tree(6, 3).unwrap(), "Card 6\n");
+
+        let dot = cascade.to_dot(3, 5).unwrap();
+        assert!(dot.starts_with("digraph scratchcards {\n"));
+        assert!(dot.contains("\t\"Card 4\" [label=\"Card 4\\n8 copies\"];\n"));
+        assert!(dot.contains("\t\"Card 3\" -> \"Card 5\" [label=\"4\"];\n"));
+        assert!(!dot.contains("Card 2"));
+        assert_eq!(dot.matches("->").count(), 3);
+        assert!(cascade.to_dot(5, 3).is_err());
+        assert!(cascade.to_dot(10, 20).is_err());
+    }
+}
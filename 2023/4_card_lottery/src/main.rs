@@ -0,0 +1,86 @@
+use std::env;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use card_lottery::{
+    card::{CardVec, Validation},
+    cascade::Cascade,
+};
+
+const TESTFILEPATH: &str = "./test_input.txt";
+const FILEPATH: &str = "./input.txt";
+
+fn main() {
+    let args = env::args().collect::<Vec<String>>();
+    let part = match get_arg_value(&args, "--part") {
+        Some(p) => p.parse::<u8>().unwrap(),
+        None => 1,
+    };
+    let is_test = args.contains(&"-t".to_string());
+    let file_path = match is_test {
+        true => TESTFILEPATH,
+        _ => FILEPATH,
+    };
+    let debug_print = is_test || args.contains(&"-d".to_string());
+    let buf = BufReader::new(open_file(file_path));
+
+    // --lenient skips cards that fail validation and lists them, rather than failing.
+    let validation = match args.contains(&"--lenient".to_string()) {
+        true => Validation::Lenient,
+        false => Validation::Strict,
+    };
+    let cards = CardVec::parse(buf, validation, debug_print).unwrap();
+    if !cards.skipped().is_empty() {
+        println!("Skipped {} cards:", cards.skipped().len());
+        for card in cards.skipped() {
+            println!("{}\n\t{}", card, card.text);
+        }
+    }
+
+    // --from <id> shows where a card's copies came from, --tree <id> follows one copy of a
+    // card down --depth levels of wins, and --dot renders the cascade from card --first to
+    // card --last as a Graphviz digraph.
+    let parse_id = |flag: &str| get_arg_value(&args, flag).map(|id| id.parse::<usize>().unwrap());
+    if let Some(id) = parse_id("--from") {
+        let cascade = Cascade::new(&cards).unwrap();
+        println!("{}", cascade.get_provenance(id).unwrap());
+        return;
+    }
+    if let Some(id) = parse_id("--tree") {
+        let depth = parse_id("--depth").unwrap_or(3);
+        let cascade = Cascade::new(&cards).unwrap();
+        print!("{}", cascade.to_tree(id, depth).unwrap());
+        return;
+    }
+    if args.contains(&"--dot".to_string()) {
+        let first = parse_id("--first").unwrap_or(1);
+        let last = parse_id("--last").unwrap_or(usize::MAX);
+        let cascade = Cascade::new(&cards).unwrap();
+        print!("{}", cascade.to_dot(first, last).unwrap());
+        return;
+    }
+
+    let result = match part {
+        1 => cards.calc_points(debug_print) as u64,
+        2 => cards.walk(debug_print).unwrap(),
+        _ => panic!("Unknown part {}, expected 1 or 2.", part),
+    };
+    println!("{}", result);
+}
+
+fn get_arg_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    let i = args.iter().position(|a| a == flag)?;
+    return args.get(i + 1).map(|a| a.as_str());
+}
+
+fn open_file<P>(path: P) -> File
+where
+    P: AsRef<Path> + std::fmt::Display,
+{
+    let file = std::fs::File::open(&path);
+    match file {
+        Ok(file) => file,
+        Err(e) => panic!("Could not open file {}: {}", path, e),
+    }
+}
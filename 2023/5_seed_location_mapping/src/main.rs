@@ -0,0 +1,53 @@
+use std::env;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use seed_location_mapping::seed_data::SeedData;
+
+const TESTFILEPATH: &str = "./test_input.txt";
+const FILEPATH: &str = "./input.txt";
+
+fn main() {
+    let args = env::args().collect::<Vec<String>>();
+    let part = match get_arg_value(&args, "--part") {
+        Some(p) => p.parse::<u8>().unwrap(),
+        None => 1,
+    };
+    // Part 2 reads the seed numbers as pairs of a range start and a length.
+    let as_ranges = match part {
+        1 => false,
+        2 => true,
+        _ => panic!("Unknown part {}, expected 1 or 2.", part),
+    };
+    let is_test = args.contains(&"-t".to_string());
+    let file_path = match is_test {
+        true => TESTFILEPATH,
+        _ => FILEPATH,
+    };
+    let debug_print = args.contains(&"-d".to_string());
+
+    let buf = BufReader::new(open_file(file_path));
+    let seed_data = SeedData::parse(buf, debug_print).unwrap();
+    let lowest = seed_data
+        .get_lowest_location(as_ranges, debug_print)
+        .unwrap();
+
+    println!("{}", lowest);
+}
+
+fn get_arg_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    let i = args.iter().position(|a| a == flag)?;
+    return args.get(i + 1).map(|a| a.as_str());
+}
+
+fn open_file<P>(path: P) -> File
+where
+    P: AsRef<Path> + std::fmt::Display,
+{
+    let file = std::fs::File::open(&path);
+    match file {
+        Ok(file) => file,
+        Err(e) => panic!("Could not open file {}: {}", path, e),
+    }
+}
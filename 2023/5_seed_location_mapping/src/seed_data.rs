@@ -1,37 +1,9 @@
-use std::env;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
-use std::path::Path;
-
-const TESTFILEPATH: &str = "./test_input.txt";
-const FILEPATH: &str = "./input.txt";
-
-fn main() {
-    let args = env::args().collect::<Vec<String>>();
-    let is_test = args.contains(&"-t".to_string());
-    let file_path = match is_test {
-        true => TESTFILEPATH,
-        _ => FILEPATH,
-    };
-    let debug_print = args.contains(&"-d".to_string());
-
-    let buf = BufReader::new(open_file(file_path));
-    let seed_data = SeedData::parse(buf, debug_print).unwrap();
-    let seed_locs = seed_data.get_seed_location_data(debug_print).unwrap();
-
-    let mut lowest = u32::MAX;
-    for [_, loc] in seed_locs {
-        if loc < lowest {
-            lowest = loc;
-        }
-    }
-
-    println!("{}", lowest);
-}
 
 pub struct SeedData {
-    seeds: Vec<u32>,
-    dest_source_cats: [Vec<[u32; 3]>; 7],
+    seeds: Vec<u64>,
+    dest_source_cats: [Vec<[u64; 3]>; 7],
 }
 impl SeedData {
     pub fn parse(buf: BufReader<File>, debug_print: bool) -> Result<Self, String> {
@@ -41,22 +13,17 @@ impl SeedData {
         for (i, l) in buf.lines().enumerate() {
             match l {
                 Ok(l) => {
-                    if l.len() == 0 {
+                    if l.is_empty() {
                         dest_source_index += 1;
                         continue;
                     }
 
                     if i == 0 {
-                        result.seeds = match Self::parse_seeds(l) {
-                            Ok(seeds) => seeds,
-                            Err(e) => return Err(e),
-                        };
+                        result.seeds = Self::parse_seeds(l)?;
                         continue;
                     }
 
-                    if let Err(e) = result.parse_dest_source(l, dest_source_index as usize) {
-                        return Err(e);
-                    }
+                    result.parse_dest_source(l, dest_source_index as usize)?;
                 }
                 Err(e) => return Err(e.to_string()),
             }
@@ -68,11 +35,11 @@ impl SeedData {
         return Ok(result);
     }
 
-    pub fn get_seed_location_data(&self, debug_print: bool) -> Result<Vec<[u32; 2]>, String> {
-        if self.seeds.len() == 0 {
+    pub fn get_seed_location_data(&self, debug_print: bool) -> Result<Vec<[u64; 2]>, String> {
+        if self.seeds.is_empty() {
             return Err("Seeds have not been parsed! get_seed_location_data failed.".to_string());
         }
-        if self.dest_source_cats.len() == 0 {
+        if self.dest_source_cats.is_empty() {
             return Err(
                 "List of dest_sources have not been parsed! get_seed_location_data failed."
                     .to_string(),
@@ -107,10 +74,10 @@ impl SeedData {
     }
 
     fn parse_dest_if_mentioned(
-        source: u32,
-        dest_source_cat: &Vec<[u32; 3]>,
+        source: u64,
+        dest_source_cat: &[[u64; 3]],
         debug_print: bool,
-    ) -> Option<u32> {
+    ) -> Option<u64> {
         let mut lowest_dest_source_cat_val = None;
         for dest_source in dest_source_cat.iter() {
             if debug_print {
@@ -138,6 +105,137 @@ impl SeedData {
         return lowest_dest_source_cat_val;
     }
 
+    /// The seeds as `(start, length)` ranges. Each seed number is a range of one, unless
+    /// `as_ranges` is set, in which case the numbers pair up into a start and a length as in
+    /// part 2.
+    pub fn get_seed_ranges(&self, as_ranges: bool) -> Result<Vec<(u64, u64)>, String> {
+        if !as_ranges {
+            return Ok(self.seeds.iter().map(|&s| (s, 1)).collect());
+        }
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(format!(
+                "Seeds can't be read as ranges, there are an odd number of them: {}",
+                self.seeds.len()
+            ));
+        }
+        return Ok(self.seeds.chunks(2).map(|c| (c[0], c[1])).collect());
+    }
+
+    pub fn get_lowest_location(&self, as_ranges: bool, debug_print: bool) -> Result<u64, String> {
+        if self.seeds.is_empty() {
+            return Err("Seeds have not been parsed! get_lowest_location failed.".to_string());
+        }
+
+        if debug_print {
+            println!("Starting get_lowest_seed_location...");
+        }
+
+        let mut result = u64::MAX;
+
+        if !as_ranges {
+            for [_, loc] in self.get_seed_location_data(debug_print)? {
+                if loc < result {
+                    result = loc;
+                }
+            }
+            return Ok(result);
+        }
+
+        for seed_range in self.get_seed_ranges(as_ranges)? {
+            let curr_range_lowest = self.parse_lowest_seed_loc(seed_range, debug_print);
+            if curr_range_lowest < result {
+                result = curr_range_lowest;
+            }
+        }
+
+        return Ok(result);
+    }
+
+    fn parse_lowest_seed_loc(&self, seed_range: (u64, u64), debug_print: bool) -> u64 {
+        let mut loc_ranges = vec![seed_range];
+        for i in 0..self.dest_source_cats.len() {
+            if debug_print {
+                println!(
+                    "Starting category {} for seed_range ({}, {})",
+                    i + 1,
+                    seed_range.0,
+                    seed_range.1
+                );
+            }
+
+            loc_ranges = loc_ranges
+                .iter()
+                .flat_map(|r| self.parse_source_range_to_dest_ranges(*r, i))
+                .collect();
+
+            if debug_print {
+                println!(
+                    "state after running category {} calculations: {:?}",
+                    i + 1,
+                    loc_ranges
+                );
+            }
+        }
+
+        let mut result = u64::MAX;
+        for (loc_min, _) in loc_ranges {
+            if loc_min < result {
+                result = loc_min;
+            }
+        }
+        if debug_print {
+            println!(
+                "lowest for seed_range ({}, {}): {}",
+                seed_range.0, seed_range.1, result
+            );
+        }
+        return result;
+    }
+
+    /// Maps a `(start, length)` range of sources through one category. The parts of the
+    /// range covered by a mapping are shifted to its destination and the parts no mapping
+    /// covers pass through unchanged. Ranges and mappings reaching past `u64::MAX` are cut
+    /// off there.
+    fn parse_source_range_to_dest_ranges(
+        &self,
+        source_range: (u64, u64),
+        dest_source_cat_index: usize,
+    ) -> Vec<(u64, u64)> {
+        let mut result = vec![];
+        let (start, len) = source_range;
+        let mut unmapped = vec![(start, len.min(u64::MAX - start))];
+
+        for dest_source in self.dest_source_cats[dest_source_cat_index].iter() {
+            let (dest, source, range) = (dest_source[0], dest_source[1], dest_source[2]);
+            let mut leftover = vec![];
+
+            for (start, len) in unmapped {
+                let end = start.saturating_add(len);
+                let overlap_start = start.max(source);
+                let overlap_end = end.min(source.saturating_add(range));
+                if overlap_start >= overlap_end {
+                    leftover.push((start, len));
+                    continue;
+                }
+
+                let mapped_start = dest.saturating_add(overlap_start - source);
+                let mapped_len = (overlap_end - overlap_start).min(u64::MAX - mapped_start);
+                result.push((mapped_start, mapped_len));
+                if start < overlap_start {
+                    leftover.push((start, overlap_start - start));
+                }
+                if overlap_end < end {
+                    leftover.push((overlap_end, end - overlap_end));
+                }
+            }
+
+            unmapped = leftover;
+        }
+
+        result.extend(unmapped);
+        return result;
+    }
+
     fn new() -> Self {
         return Self {
             seeds: Vec::new(),
@@ -153,8 +251,8 @@ impl SeedData {
         };
     }
 
-    fn parse_seeds(l: String) -> Result<Vec<u32>, String> {
-        if l.len() == 0 {
+    fn parse_seeds(l: String) -> Result<Vec<u64>, String> {
+        if l.is_empty() {
             return Ok(vec![]);
         }
 
@@ -173,17 +271,17 @@ impl SeedData {
                 '0'..='9' => {
                     numeric_string.push(c);
                     if i + 1 == l.len() {
-                        match numeric_string.parse::<u32>() {
+                        match numeric_string.parse::<u64>() {
                             Ok(n) => result.push(n),
                             Err(e) => return Err(e.to_string()),
                         }
                     }
                 }
                 _ => {
-                    if numeric_string.len() == 0 {
+                    if numeric_string.is_empty() {
                         continue;
                     }
-                    match numeric_string.parse::<u32>() {
+                    match numeric_string.parse::<u64>() {
                         Ok(n) => result.push(n),
                         Err(e) => return Err(e.to_string()),
                     }
@@ -199,11 +297,11 @@ impl SeedData {
         if dest_source_index > 6 {
             return Err("Cannot pass an index higher than 6 into parse_dest_source".to_string());
         }
-        if l.len() == 0 {
+        if l.is_empty() {
             return Ok(());
         }
 
-        let mut result: [u32; 3] = [0, 0, 0];
+        let mut result: [u64; 3] = [0, 0, 0];
         let mut result_index = 0;
         let mut numeric_string = "".to_string();
 
@@ -211,7 +309,7 @@ impl SeedData {
             match c {
                 '0'..='9' => numeric_string.push(c),
                 ' ' => {
-                    match numeric_string.parse::<u32>() {
+                    match numeric_string.parse::<u64>() {
                         Ok(n) => result[result_index] = n,
                         Err(e) => return Err(e.to_string()),
                     };
@@ -222,8 +320,8 @@ impl SeedData {
             }
         }
 
-        if numeric_string.len() != 0 {
-            match numeric_string.parse::<u32>() {
+        if !numeric_string.is_empty() {
+            match numeric_string.parse::<u64>() {
                 Ok(n) => result[2] = n,
                 Err(e) => return Err(e.to_string()),
             }
@@ -257,29 +355,33 @@ impl std::fmt::Display for SeedData {
         let result = format!(
             "{}Categories:\n{}",
             seeds_to_string(),
-            (0..7).map(|i| cat_to_string(i)).collect::<String>()
+            (0..7).map(cat_to_string).collect::<String>()
         );
 
         write!(f, "{}", result)
     }
 }
 
-fn open_file<P>(path: P) -> File
-where
-    P: AsRef<Path> + std::fmt::Display,
-{
-    let file = std::fs::File::open(&path);
-    match file {
-        Ok(file) => file,
-        Err(e) => panic!("Could not open file {}: {}", path, e),
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::{open_file, SeedData, TESTFILEPATH};
+    use crate::seed_data::SeedData;
     use std::fmt::Debug;
+    use std::fs::File;
     use std::io::BufReader;
+    use std::path::Path;
+
+    const TESTFILEPATH: &str = "./test_input.txt";
+
+    pub fn open_file<P>(path: P) -> File
+    where
+        P: AsRef<Path> + std::fmt::Display,
+    {
+        let file = std::fs::File::open(&path);
+        match file {
+            Ok(file) => file,
+            Err(e) => panic!("Could not open file {}: {}", path, e),
+        }
+    }
 
     #[test]
     fn test_seed_data_parse() {
@@ -326,7 +428,7 @@ mod tests {
         let seed_data = SeedData::parse(buf, false).unwrap();
         let seed_locs = seed_data.get_seed_location_data(false).unwrap();
 
-        let mut lowest = u32::MAX;
+        let mut lowest = u64::MAX;
         for seed_loc in seed_locs {
             if seed_loc[1] < lowest {
                 lowest = seed_loc[1];
@@ -334,10 +436,47 @@ mod tests {
         }
 
         assert_eq!(lowest, 35);
+        assert_eq!(seed_data.get_lowest_location(false, false), Ok(35));
+    }
+
+    #[test]
+    fn test_input_seed_ranges() {
+        let buf = BufReader::new(open_file(TESTFILEPATH));
+        let seed_data = SeedData::parse(buf, false).unwrap();
+        assert_eq!(
+            seed_data.get_seed_ranges(true),
+            Ok(vec![(79, 14), (55, 13)])
+        );
+        assert_eq!(seed_data.get_lowest_location(true, false), Ok(46));
+    }
+
+    #[test]
+    fn test_source_range_to_dest_ranges() {
+        let buf = BufReader::new(open_file(TESTFILEPATH));
+        let seed_data = SeedData::parse(buf, false).unwrap();
+        // Seed to soil maps 98..100 to 50..52 and 50..98 to 52..100.
+        let map = |range: (u64, u64)| {
+            let mut result = seed_data.parse_source_range_to_dest_ranges(range, 0);
+            result.sort();
+            return result;
+        };
+
+        // Fully inside one mapping.
+        assert_eq!(map((79, 14)), vec![(81, 14)]);
+        // Straddling the bottom of a mapping, the part below it passes through unchanged.
+        assert_eq!(map((45, 10)), vec![(45, 5), (52, 5)]);
+        // Straddling the boundary between two mappings, each part takes its own.
+        assert_eq!(map((96, 4)), vec![(50, 2), (98, 2)]);
+        // Straddling the top of the last mapping.
+        assert_eq!(map((99, 3)), vec![(51, 1), (100, 2)]);
+        // Ending right where a mapping starts doesn't overlap it.
+        assert_eq!(map((40, 10)), vec![(40, 10)]);
+        // Running past the largest number stops there rather than overflowing.
+        assert_eq!(map((u64::MAX - 5, 10)), vec![(u64::MAX - 5, 5)]);
     }
 
     #[derive(Debug)]
-    struct SeedVec(Vec<u32>);
+    struct SeedVec(Vec<u64>);
     impl std::cmp::Eq for SeedVec {}
     impl std::cmp::PartialEq for SeedVec {
         fn eq(&self, other: &Self) -> bool {
@@ -354,7 +493,7 @@ mod tests {
     }
 
     #[derive(Debug)]
-    struct SourceDestVec(Vec<[u32; 3]>);
+    struct SourceDestVec(Vec<[u64; 3]>);
     impl std::cmp::Eq for SourceDestVec {}
     impl std::cmp::PartialEq for SourceDestVec {
         fn eq(&self, other: &Self) -> bool {
@@ -374,7 +513,7 @@ mod tests {
     }
 
     #[derive(Debug)]
-    struct SeedLocVec(Vec<[u32; 2]>);
+    struct SeedLocVec(Vec<[u64; 2]>);
     impl std::cmp::Eq for SeedLocVec {}
     impl std::cmp::PartialEq for SeedLocVec {
         fn eq(&self, other: &Self) -> bool {
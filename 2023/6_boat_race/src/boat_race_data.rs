@@ -0,0 +1,333 @@
+use crate::race_table::RaceTable;
+use aoc_utils::math;
+use std::io::BufRead;
+
+enum BoatDataType {
+    Time(Vec<u128>),
+    Distance(Vec<u128>),
+}
+
+/// How the numbers on a race sheet are read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Each column is its own race, as in part 1.
+    SeparateRaces,
+    /// The spaces between digits are ignored, so each line holds one number and the
+    /// sheet is a single race, as in part 2.
+    SingleRace,
+}
+
+pub struct BoatRaceData {
+    times: Vec<u128>,
+    distances: Vec<u128>,
+}
+impl BoatRaceData {
+    /// Reads a sheet with one `Time:` and one `Distance:` line, in either order. Blank
+    /// lines are skipped, and any other label, a repeated label or a different number of
+    /// times and distances is an error.
+    pub fn parse<R: BufRead>(buf: R, mode: ParseMode, debug_print: bool) -> Result<Self, String> {
+        let mut times = None;
+        let mut distances = None;
+
+        for l in buf.lines() {
+            let l = match l {
+                Ok(line) => line,
+                Err(e) => return Err(e.to_string()),
+            };
+            if l.trim().is_empty() {
+                continue;
+            }
+
+            let data_group = Self::parse_line(&l, mode)?;
+            use BoatDataType::*;
+            if debug_print {
+                match &data_group {
+                    Time(d) => println!("Parsed Time: {:?}", d),
+                    Distance(d) => println!("Parsed Distance: {:?}", d),
+                }
+            }
+            let (slot, label, data) = match data_group {
+                Time(data) => (&mut times, "Time", data),
+                Distance(data) => (&mut distances, "Distance", data),
+            };
+            if slot.is_some() {
+                return Err(format!("The sheet has more than one {} line.", label));
+            }
+            *slot = Some(data);
+        }
+
+        let (times, distances) = match (times, distances) {
+            (Some(times), Some(distances)) => (times, distances),
+            (None, _) => return Err("The sheet has no Time line.".to_string()),
+            (_, None) => return Err("The sheet has no Distance line.".to_string()),
+        };
+        if distances.len() != times.len() {
+            return Err(format!(
+                "The sheet has {} times but {} distances.",
+                times.len(),
+                distances.len()
+            ));
+        }
+
+        return Ok(Self { times, distances });
+    }
+
+    /// Product of how many hold times beat the record in each race, which is 0 if any race
+    /// can't be won.
+    pub fn calc_margin_product(&self, debug_print: bool) -> Result<u128, String> {
+        let windows = self.get_charge_time_ranges(debug_print)?;
+        let mut result: u128 = 1;
+        for window in windows {
+            let solutions = match window {
+                Some((low, high)) => high - low + 1,
+                None => 0,
+            };
+            result = match result.checked_mul(solutions) {
+                Some(n) => n,
+                None => return Err("The margin product overflowed a u128.".to_string()),
+            };
+        }
+        return Ok(result);
+    }
+
+    /// The inclusive range of hold times that beat the record in each race, or `None`
+    /// for a race that can't be won.
+    pub fn get_charge_time_ranges(
+        &self,
+        debug_print: bool,
+    ) -> Result<Vec<Option<(u128, u128)>>, String> {
+        let mut result = vec![];
+
+        for i in 0..self.times.len() {
+            let (time, distance) = (self.times[i], self.distances[i]);
+            let window = Self::calc_window(time, distance)?;
+            if debug_print {
+                match window {
+                    Some((low, high)) => println!(
+                        "Time {}, Distance {}: hold {} to {}, {} * {} = {} beats it but {} * {} = {} doesn't",
+                        time,
+                        distance,
+                        low,
+                        high,
+                        low,
+                        time - low,
+                        low * (time - low),
+                        low - 1,
+                        time - low + 1,
+                        (low - 1) * (time - low + 1)
+                    ),
+                    None => println!(
+                        "Time {}, Distance {}: the record can't be beaten",
+                        time, distance
+                    ),
+                }
+            }
+            result.push(window);
+        }
+
+        return Ok(result);
+    }
+
+    /// A table of hold times against distances for each race, of about `max_rows` rows.
+    pub fn get_race_tables(&self, max_rows: usize) -> Result<Vec<RaceTable>, String> {
+        return (0..self.times.len())
+            .map(|i| RaceTable::new(self.times[i], self.distances[i], max_rows))
+            .collect();
+    }
+
+    /// Solves `h * (time - h) > distance` for the inclusive range of winning hold times
+    /// `h`, or `None` if there are none. Rearranged, a hold wins when
+    /// `(time - 2h)^2 < time^2 - 4 * distance`, so the window is centred on `time / 2` and
+    /// reaches out to the largest `x = |time - 2h|` whose square is under the
+    /// discriminant and which has the same parity as `time`. Times from 2^64 up have
+    /// squares too big for a u128 and are rejected.
+    pub fn calc_window(time: u128, distance: u128) -> Result<Option<(u128, u128)>, String> {
+        let time_squared = match time.checked_mul(time) {
+            Some(n) => n,
+            None => return Err(format!("Race time {} is too long to solve exactly.", time)),
+        };
+        // A distance too big to quadruple is further than any hold time reaches anyway.
+        let discriminant = match distance
+            .checked_mul(4)
+            .and_then(|d| time_squared.checked_sub(d))
+        {
+            Some(n) if n > 0 => n,
+            _ => return Ok(None),
+        };
+
+        let mut reach = math::isqrt(discriminant - 1);
+        if reach % 2 != time % 2 {
+            match reach.checked_sub(1) {
+                Some(n) => reach = n,
+                None => return Ok(None),
+            }
+        }
+
+        return Ok(Some(((time - reach) / 2, (time + reach) / 2)));
+    }
+
+    fn parse_line(line: &str, mode: ParseMode) -> Result<BoatDataType, String> {
+        let (label, numbers) = match line.split_once(':') {
+            Some((label, numbers)) => (label.trim(), numbers),
+            None => return Err(format!("Line does not have a label: {}", line)),
+        };
+        if label != "Time" && label != "Distance" {
+            return Err(format!(
+                "Unknown label {}, expected Time or Distance: {}",
+                label, line
+            ));
+        }
+
+        let mut words = numbers.split_whitespace().collect::<Vec<&str>>();
+        if let Some(c) = words
+            .iter()
+            .flat_map(|w| w.chars())
+            .find(|c| !c.is_ascii_digit())
+        {
+            return Err(format!(
+                "Unexpected character {:?} on the {} line.",
+                c, label
+            ));
+        }
+        let joined = words.concat();
+        if mode == ParseMode::SingleRace && !joined.is_empty() {
+            words = vec![&joined];
+        }
+        if words.is_empty() {
+            return Err(format!("The {} line has no numbers.", label));
+        }
+
+        let mut result = vec![];
+        for word in words {
+            match word.parse::<u128>() {
+                Ok(n) => result.push(n),
+                Err(e) => return Err(format!("Invalid {} {}: {}", label, word, e)),
+            }
+        }
+
+        return Ok(match label {
+            "Time" => BoatDataType::Time(result),
+            _ => BoatDataType::Distance(result),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::boat_race_data::{BoatRaceData, ParseMode};
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::path::Path;
+
+    pub fn open_file<P>(path: P) -> File
+    where
+        P: AsRef<Path> + std::fmt::Display,
+    {
+        let file = std::fs::File::open(&path);
+        match file {
+            Ok(file) => file,
+            Err(e) => panic!("Could not open file {}: {}", path, e),
+        }
+    }
+
+    #[test]
+    fn test_margin_product_separate_races() {
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        let data = BoatRaceData::parse(buf, ParseMode::SeparateRaces, false).unwrap();
+        assert_eq!(
+            data.get_charge_time_ranges(false).unwrap(),
+            vec![Some((2, 5)), Some((4, 11)), Some((11, 19))]
+        );
+        assert_eq!(data.calc_margin_product(false), Ok(288));
+    }
+
+    #[test]
+    fn test_margin_product_single_race() {
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        let data = BoatRaceData::parse(buf, ParseMode::SingleRace, false).unwrap();
+        assert_eq!(
+            data.get_charge_time_ranges(false).unwrap(),
+            vec![Some((14, 71516))]
+        );
+        assert_eq!(data.calc_margin_product(false), Ok(71503));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse = |sheet: &str, mode| BoatRaceData::parse(sheet.as_bytes(), mode, false);
+        let separate = ParseMode::SeparateRaces;
+
+        let data = parse("\nDistance: 9 40\nTime: 7 15\n", separate).unwrap();
+        assert_eq!(data.calc_margin_product(false), Ok(32));
+        let error = |sheet: &str| parse(sheet, separate).err().unwrap();
+        assert_eq!(
+            error("Time: 7 15\nDistance: 9\n"),
+            "The sheet has 2 times but 1 distances."
+        );
+        assert!(error("Time: 7\nSpeed: 9\n").starts_with("Unknown label Speed"));
+        assert!(error("Tim: 7\nDistance: 9\n").starts_with("Unknown label Tim"));
+        assert_eq!(
+            error("Time: 7\nTime: 8\nDistance: 9\n"),
+            "The sheet has more than one Time line."
+        );
+        assert_eq!(error("Time: 7\n"), "The sheet has no Distance line.");
+        assert_eq!(
+            error("Time:\nDistance: 9\n"),
+            "The Time line has no numbers."
+        );
+        assert!(error("Time: 7 -1\nDistance: 9 1\n").starts_with("Unexpected character '-'"));
+        assert!(error("Time 7\nDistance: 9\n").starts_with("Line does not have a label"));
+
+        let data = parse("Time: 7 15\nDistance: 9 40\n", ParseMode::SingleRace).unwrap();
+        assert_eq!(
+            data.get_charge_time_ranges(false).unwrap(),
+            vec![Some((2, 713))]
+        );
+    }
+
+    /// Scans every hold time for the winning ones.
+    fn brute_force_window(products: &[u128], distance: u128) -> Option<(u128, u128)> {
+        let low = products.iter().position(|&p| p > distance)?;
+        let high = products.iter().rposition(|&p| p > distance)?;
+        return Some((low as u128, high as u128));
+    }
+
+    #[test]
+    fn test_window_matches_brute_force() {
+        for time in 0..3000u128 {
+            let products = (0..=time).map(|h| h * (time - h)).collect::<Vec<u128>>();
+
+            // Check just either side of the distance each hold time reaches, which is where
+            // the window's edges move.
+            let mut distances = vec![0, 1];
+            for h in [1, time / 7, time / 3, time / 2, time - time / 2] {
+                let reached = h.min(time) * (time - h.min(time));
+                distances.extend([reached.saturating_sub(1), reached, reached + 1]);
+            }
+            for distance in distances {
+                assert_eq!(
+                    BoatRaceData::calc_window(time, distance).unwrap(),
+                    brute_force_window(&products, distance),
+                    "time {}, distance {}",
+                    time,
+                    distance
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_window_u128() {
+        // Only the middle hold time reaches a distance one short of the best possible.
+        let time = 2 * (u64::MAX as u128 / 2);
+        let middle = time / 2;
+        assert_eq!(
+            BoatRaceData::calc_window(time, middle * middle - 1),
+            Ok(Some((middle, middle)))
+        );
+        assert_eq!(BoatRaceData::calc_window(time, middle * middle), Ok(None));
+        assert_eq!(BoatRaceData::calc_window(time, 0), Ok(Some((1, time - 1))));
+        assert_eq!(BoatRaceData::calc_window(3, u128::MAX), Ok(None));
+        assert!(BoatRaceData::calc_window(1 << 64, 0).is_err());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod boat_race_data;
+pub mod race_table;
@@ -0,0 +1,76 @@
+use std::env;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use boat_race::{
+    boat_race_data::{BoatRaceData, ParseMode},
+    race_table,
+};
+
+const TESTFILEPATH: &str = "./test_input.txt";
+const FILEPATH: &str = "./input.txt";
+const CHART_WIDTH: usize = 60;
+
+fn main() {
+    let args = env::args().collect::<Vec<String>>();
+    let part = match get_arg_value(&args, "--part") {
+        Some(p) => p.parse::<u8>().unwrap(),
+        None => 1,
+    };
+    // Part 2 reads the same sheet as one race, ignoring the spaces between digits.
+    let mode = match part {
+        1 => ParseMode::SeparateRaces,
+        2 => ParseMode::SingleRace,
+        _ => panic!("Unknown part {}, expected 1 or 2.", part),
+    };
+    let is_test = args.contains(&"-t".to_string());
+    let file_path = match is_test {
+        true => TESTFILEPATH,
+        false => FILEPATH,
+    };
+    let debug_print = args.contains(&"-d".to_string());
+
+    let buf = BufReader::new(open_file(file_path));
+    let data = BoatRaceData::parse(buf, mode, debug_print).unwrap();
+
+    // --table charts each race's hold times against the distances they reach, or --csv
+    // lists them, sampling long races down to about --rows holds.
+    let as_chart = args.contains(&"--table".to_string());
+    if as_chart || args.contains(&"--csv".to_string()) {
+        let max_rows = match get_arg_value(&args, "--rows") {
+            Some(n) => n.parse::<usize>().unwrap(),
+            None => 40,
+        };
+        let tables = data.get_race_tables(max_rows).unwrap();
+        match as_chart {
+            true => {
+                for table in tables.iter() {
+                    println!("{}", table.to_chart(CHART_WIDTH));
+                }
+            }
+            false => print!("{}", race_table::to_csv(&tables)),
+        }
+        return;
+    }
+
+    let result = data.calc_margin_product(debug_print).unwrap();
+
+    println!("{}", result);
+}
+
+fn get_arg_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    let i = args.iter().position(|a| a == flag)?;
+    return args.get(i + 1).map(|a| a.as_str());
+}
+
+fn open_file<P>(path: P) -> File
+where
+    P: AsRef<Path> + std::fmt::Display,
+{
+    let file = std::fs::File::open(&path);
+    match file {
+        Ok(file) => file,
+        Err(e) => panic!("Could not open file {}: {}", path, e),
+    }
+}
@@ -0,0 +1,208 @@
+use crate::boat_race_data::BoatRaceData;
+
+/// How far one hold time takes the boat.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RaceRow {
+    pub hold: u128,
+    pub distance: u128,
+    pub beats_record: bool,
+    pub optimal: bool,
+}
+
+/// Every hold time in a race against the distance it reaches. Long races are sampled
+/// down to at most about `max_rows` evenly spaced holds, always keeping the edges of the
+/// winning window and the optimal holds.
+pub struct RaceTable {
+    time: u128,
+    record: u128,
+    window: Option<(u128, u128)>,
+    rows: Vec<RaceRow>,
+}
+impl RaceTable {
+    pub fn new(time: u128, record: u128, max_rows: usize) -> Result<Self, String> {
+        if max_rows < 2 {
+            return Err(format!(
+                "A race table needs at least 2 rows, not {}.",
+                max_rows
+            ));
+        }
+        let window = BoatRaceData::calc_window(time, record)?;
+        let optimal = [time / 2, time - time / 2];
+
+        let step = time.div_ceil(max_rows as u128 - 1).max(1);
+        let mut holds = (0..=time).step_by(step as usize).collect::<Vec<u128>>();
+        holds.push(time);
+        holds.extend(optimal);
+        if let Some((low, high)) = window {
+            holds.extend([low - 1, low, high, high + 1]);
+        }
+        holds.sort();
+        holds.dedup();
+
+        let rows = holds
+            .into_iter()
+            .map(|hold| {
+                let distance = hold * (time - hold);
+                return RaceRow {
+                    hold,
+                    distance,
+                    beats_record: distance > record,
+                    optimal: optimal.contains(&hold),
+                };
+            })
+            .collect();
+
+        return Ok(Self {
+            time,
+            record,
+            window,
+            rows,
+        });
+    }
+
+    pub fn rows(&self) -> &[RaceRow] {
+        return &self.rows;
+    }
+
+    /// The inclusive range of hold times that beat the record, if any do.
+    pub fn window(&self) -> Option<(u128, u128)> {
+        return self.window;
+    }
+
+    /// How many hold times beat the record.
+    pub fn margin(&self) -> u128 {
+        return match self.window {
+            Some((low, high)) => high - low + 1,
+            None => 0,
+        };
+    }
+
+    /// The furthest any hold time reaches, holding for half the race.
+    pub fn best_distance(&self) -> u128 {
+        return (self.time / 2) * (self.time - self.time / 2);
+    }
+
+    /// A summary line followed by one bar per row, scaled so the best distance is `width`
+    /// characters long. Bars are `#` for holds that beat the record and `=` for those that
+    /// don't, with a `:` where the bar falls short of the record, and optimal holds are
+    /// marked `best`.
+    pub fn to_chart(&self, width: usize) -> String {
+        let winning = match self.window {
+            Some((low, high)) => {
+                format!("holds {} to {} win ({} ways)", low, high, self.margin())
+            }
+            None => "no hold wins".to_string(),
+        };
+        let optimal = match self.time % 2 {
+            0 => format!("{}", self.time / 2),
+            _ => format!("{} or {}", self.time / 2, self.time - self.time / 2),
+        };
+        let mut result = format!(
+            "Time {}, record {}: {}, best hold {} reaches {}\n",
+            self.time,
+            self.record,
+            winning,
+            optimal,
+            self.best_distance()
+        );
+
+        // A record past the best distance is drawn at the end of the chart.
+        let scale = |distance: u128| match self.best_distance() {
+            0 => 0,
+            best => (distance.min(best) as f64 / best as f64 * width as f64) as usize,
+        };
+        let record_column = scale(self.record);
+        let hold_width = self.time.to_string().len();
+        for row in self.rows.iter() {
+            let length = scale(row.distance);
+            let fill = if row.beats_record { '#' } else { '=' };
+            let bar = (0..=width)
+                .map(|i| match (i < length, i == record_column) {
+                    (true, _) => fill,
+                    (false, true) => ':',
+                    (false, false) => ' ',
+                })
+                .collect::<String>();
+
+            let mark = if row.optimal { " best" } else { "" };
+            result = format!(
+                "{}{:>hold_width$} |{} {}{}\n",
+                result, row.hold, bar, row.distance, mark
+            );
+        }
+
+        return result;
+    }
+}
+
+/// Every row of every race as CSV, numbering races from 1.
+pub fn to_csv(tables: &[RaceTable]) -> String {
+    let mut result = "race,time,record,hold,distance,beats_record,optimal\n".to_string();
+    for (i, table) in tables.iter().enumerate() {
+        for row in table.rows.iter() {
+            result = format!(
+                "{}{},{},{},{},{},{},{}\n",
+                result,
+                i + 1,
+                table.time,
+                table.record,
+                row.hold,
+                row.distance,
+                row.beats_record,
+                row.optimal
+            );
+        }
+    }
+    return result;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::race_table::{to_csv, RaceTable};
+
+    #[test]
+    fn test_race_table() {
+        let table = RaceTable::new(7, 9, 100).unwrap();
+        assert_eq!(table.rows().len(), 8);
+        assert_eq!(table.window(), Some((2, 5)));
+        assert_eq!((table.margin(), table.best_distance()), (4, 12));
+        let winners = table.rows().iter().filter(|r| r.beats_record).count();
+        assert_eq!(winners as u128, table.margin());
+
+        assert_eq!(
+            table.to_chart(12),
+            "Time 7, record 9: holds 2 to 5 win (4 ways), best hold 3 or 4 reaches 12\n\
+             0 |         :    0\n\
+             1 |======   :    6\n\
+             2 |##########    10\n\
+             3 |############  12 best\n\
+             4 |############  12 best\n\
+             5 |##########    10\n\
+             6 |======   :    6\n\
+             7 |         :    0\n"
+        );
+
+        let csv = to_csv(&[table, RaceTable::new(2, 5, 100).unwrap()]);
+        let lines = csv.lines().collect::<Vec<&str>>();
+        assert_eq!(
+            lines[0],
+            "race,time,record,hold,distance,beats_record,optimal"
+        );
+        assert_eq!(lines[4], "1,7,9,3,12,true,true");
+        assert_eq!(lines[10], "2,2,5,1,1,false,true");
+        assert_eq!(lines.len(), 12);
+    }
+
+    #[test]
+    fn test_race_table_sampled() {
+        let table = RaceTable::new(71530, 940200, 10).unwrap();
+        let holds = table.rows().iter().map(|r| r.hold).collect::<Vec<u128>>();
+        // Every 7948th hold, plus the last, the window's edges and the middle.
+        assert_eq!(holds.len(), 15);
+        for hold in [0, 13, 14, 7948, 35765, 71516, 71517, 71530] {
+            assert!(holds.contains(&hold), "{}", hold);
+        }
+        assert_eq!(table.margin(), 71503);
+        assert!(RaceTable::new(7, 9, 1).is_err());
+    }
+}
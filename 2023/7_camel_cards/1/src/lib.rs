@@ -1,3 +0,0 @@
-pub mod card;
-pub mod hand;
-pub mod hand_data;
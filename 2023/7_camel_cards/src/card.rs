@@ -0,0 +1,22 @@
+/// A card face. Which faces make up the deck, and how they rank against each other, is
+/// up to the `Ruleset` in play.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Card(char);
+impl Card {
+    pub fn parse(c: char) -> Result<Card, String> {
+        if c.is_whitespace() || c.is_control() {
+            return Err(format!("Invalid character while parsing card: {:?}", c));
+        }
+
+        return Ok(Card(c));
+    }
+
+    pub fn to_char(&self) -> char {
+        return self.0;
+    }
+}
+impl std::fmt::Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
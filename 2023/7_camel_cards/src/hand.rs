@@ -0,0 +1,208 @@
+use crate::{card::Card, ruleset::Ruleset};
+
+/// The sizes of a hand's groups of two or more matching cards, largest first, once any
+/// wild cards have joined in. Comparing them group by group gives the usual ladder for
+/// any hand size: `[5]` five of a kind beats `[4]`, which beats `[3, 2]` full house,
+/// then `[3]`, `[2, 2]` and so on down to `[]` high card, and a six-card hand fits
+/// `[4, 2]` and `[2, 2, 2]` three pairs in the same way.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandType(Vec<u8>);
+impl HandType {
+    pub fn new(groups: &[u8]) -> Self {
+        let mut groups = groups
+            .iter()
+            .copied()
+            .filter(|&n| n > 1)
+            .collect::<Vec<u8>>();
+        groups.sort_by(|a, b| b.cmp(a));
+        return Self(groups);
+    }
+
+    /// Accepts a type's name, such as `full_house` or `three_pairs`, or its groups joined
+    /// by `+`, such as `4+2`.
+    pub fn parse(name: &str) -> Result<Self, String> {
+        let groups: &[u8] = match name {
+            "six_kind" => &[6],
+            "five_kind" => &[5],
+            "four_kind" => &[4],
+            "full_house" => &[3, 2],
+            "three_kind" => &[3],
+            "three_pairs" => &[2, 2, 2],
+            "two_pair" => &[2, 2],
+            "one_pair" => &[2],
+            "high" => &[],
+            _ => {
+                let groups = name
+                    .split('+')
+                    .map(|n| n.trim().parse::<u8>())
+                    .collect::<Result<Vec<u8>, _>>();
+                return match groups {
+                    Ok(groups) => Ok(Self::new(&groups)),
+                    Err(_) => Err(format!("Invalid hand type name: {}", name)),
+                };
+            }
+        };
+        return Ok(Self::new(groups));
+    }
+
+    pub fn groups(&self) -> &[u8] {
+        return &self.0;
+    }
+}
+impl std::fmt::Display for HandType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let number = |n: usize| -> String {
+            const NUMBERS: [&str; 11] = [
+                "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+                "ten",
+            ];
+            return match NUMBERS.get(n) {
+                Some(word) => word.to_string(),
+                None => n.to_string(),
+            };
+        };
+
+        let kinds = self.0.iter().filter(|&&n| n > 2).collect::<Vec<&u8>>();
+        let pairs = self.0.len() - kinds.len();
+        let name = match (kinds.as_slice(), pairs) {
+            ([], 0) => "high card".to_string(),
+            ([], 1) => "one pair".to_string(),
+            ([], 2) => "two pair".to_string(),
+            ([], n) => format!("{} pairs", number(n)),
+            ([3], 1) => "full house".to_string(),
+            _ => {
+                let mut parts = kinds
+                    .iter()
+                    .map(|&&n| format!("{} of a kind", number(n as usize)))
+                    .collect::<Vec<String>>();
+                match pairs {
+                    0 => (),
+                    1 => parts.push("a pair".to_string()),
+                    n => parts.push(format!("{} pairs", number(n))),
+                }
+                parts.join(" and ")
+            }
+        };
+
+        let mut chars = name.chars();
+        let name = match chars.next() {
+            Some(first) => format!("{}{}", first.to_ascii_uppercase(), chars.as_str()),
+            None => name,
+        };
+        write!(f, "{}", name)
+    }
+}
+
+pub struct Hand {
+    hand: Vec<Card>,
+    bid: u32,
+    hand_type: HandType,
+    ranks: Vec<u8>,
+}
+impl Hand {
+    /// Parses a hand and its bid, typing and ranking its cards under `ruleset`.
+    pub fn parse(l: String, ruleset: &Ruleset) -> Result<Self, String> {
+        let seperator_index = match l.find(' ') {
+            Some(i) => i,
+            None => return Err(format!("line does not contain a space separator: {}", l)),
+        };
+
+        let hand = l[0..seperator_index]
+            .chars()
+            .map(|c| ruleset.parse_card(c))
+            .collect::<Result<Vec<Card>, String>>()?;
+        let bid = match l[seperator_index + 1..l.len()].parse::<u32>() {
+            Ok(b) => b,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        return match Self::new(hand, bid, ruleset) {
+            Ok(hand) => Ok(hand),
+            Err(e) => Err(format!("{}: {}", e, l)),
+        };
+    }
+
+    /// Types and ranks `hand` under `ruleset`, checking every card is in its deck and the
+    /// hand holds as many cards as the ruleset deals.
+    pub fn new(hand: Vec<Card>, bid: u32, ruleset: &Ruleset) -> Result<Self, String> {
+        if hand.is_empty() {
+            return Err("Hand has no cards".to_string());
+        }
+        if let Some(card) = hand.iter().find(|c| !ruleset.cards().contains(c)) {
+            return Err(format!(
+                "Card {} isn't in the deck for ruleset {}",
+                card,
+                ruleset.name()
+            ));
+        }
+        if hand.len() > u8::MAX as usize {
+            return Err(format!(
+                "Hands hold at most {} cards, but found {}",
+                u8::MAX,
+                hand.len()
+            ));
+        }
+        if let Some(size) = ruleset.hand_size() {
+            if hand.len() != size {
+                return Err(format!(
+                    "Ruleset {} deals hands of {} cards, but found {}",
+                    ruleset.name(),
+                    size,
+                    hand.len()
+                ));
+            }
+        }
+
+        let hand_type = ruleset.get_type(&hand);
+        let ranks = hand.iter().map(|c| ruleset.get_rank(c)).collect();
+
+        return Ok(Self {
+            hand,
+            bid,
+            hand_type,
+            ranks,
+        });
+    }
+
+    pub fn calc_winnings(&self, rank: u32) -> u32 {
+        return self.bid * rank;
+    }
+
+    pub fn get_hand(&self) -> &[Card] {
+        return &self.hand;
+    }
+
+    pub fn get_bid(&self) -> u32 {
+        return self.bid;
+    }
+
+    pub fn get_hand_type(&self) -> &HandType {
+        return &self.hand_type;
+    }
+
+    /// Each card's rank under the ruleset the hand was parsed with, in hand order.
+    pub fn get_ranks(&self) -> &[u8] {
+        return &self.ranks;
+    }
+
+    /// Hands compare by type first, then card by card from the left by rank.
+    fn sort_key(&self) -> (&HandType, &[u8]) {
+        return (&self.hand_type, &self.ranks);
+    }
+}
+impl std::cmp::Eq for Hand {}
+impl std::cmp::PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        return self.sort_key() == other.sort_key();
+    }
+}
+impl std::cmp::Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        return self.sort_key().cmp(&other.sort_key());
+    }
+}
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        return Some(self.cmp(other));
+    }
+}
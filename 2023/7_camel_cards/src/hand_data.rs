@@ -0,0 +1,99 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use crate::{hand::Hand, ruleset::Ruleset};
+
+pub struct HandData(pub Vec<Hand>);
+impl HandData {
+    /// Parses every hand, ranking them under `ruleset`.
+    pub fn parse(buf: BufReader<File>, ruleset: &Ruleset) -> Result<Self, String> {
+        let mut result = vec![];
+
+        for l in buf.lines() {
+            let l = match l {
+                Ok(s) => s,
+                Err(e) => return Err(e.to_string()),
+            };
+
+            result.push(Hand::parse(l, ruleset)?);
+        }
+
+        return Ok(HandData(result));
+    }
+
+    pub fn sort_by_rank(&mut self) {
+        return self.0.sort();
+    }
+
+    /// Sum of every hand's bid multiplied by its rank, weakest hand first.
+    pub fn calc_total_winnings(&mut self) -> u32 {
+        self.sort_by_rank();
+
+        let mut result = 0;
+        for i in 0..self.0.len() {
+            let rank = (i + 1) as u32;
+            result += self.0[i].calc_winnings(rank);
+        }
+        return result;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hand_data::HandData;
+    use crate::ruleset::Ruleset;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::path::Path;
+
+    pub fn open_file<P>(path: P) -> File
+    where
+        P: AsRef<Path> + std::fmt::Display,
+    {
+        let file = std::fs::File::open(&path);
+        match file {
+            Ok(file) => file,
+            Err(e) => panic!("Could not open file {}: {}", path, e),
+        }
+    }
+
+    #[test]
+    fn test_total_winnings_part1() {
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        let mut hand_data = HandData::parse(buf, &Ruleset::part1()).unwrap();
+        assert_eq!(hand_data.calc_total_winnings(), 6440);
+    }
+
+    #[test]
+    fn test_total_winnings_part2() {
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        let mut hand_data = HandData::parse(buf, &Ruleset::part2()).unwrap();
+        assert_eq!(hand_data.calc_total_winnings(), 5905);
+    }
+
+    #[test]
+    fn test_total_winnings_two_wild() {
+        // The Two makes 32T3K three of a kind, lifting it above KK677.
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        let mut hand_data = HandData::parse(buf, &Ruleset::two_wild()).unwrap();
+        assert_eq!(hand_data.calc_total_winnings(), 6642);
+    }
+
+    #[test]
+    fn test_total_winnings_six_card() {
+        let ruleset = Ruleset::parse(BufReader::new(open_file("./test_rules_six.txt"))).unwrap();
+        let buf = BufReader::new(open_file("./test_input_six.txt"));
+        let mut hand_data = HandData::parse(buf, &ruleset).unwrap();
+        // One pair, three pairs, full house, then the wild Joker's five of a kind and six
+        // of a kind.
+        assert_eq!(
+            hand_data.calc_total_winnings(),
+            50 + 20 * 2 + 30 * 3 + 40 * 4 + 10 * 5
+        );
+
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        assert!(HandData::parse(buf, &ruleset).is_err());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod card;
+pub mod hand;
+pub mod hand_data;
+pub mod reference;
+pub mod report;
+pub mod ruleset;
+pub mod simulator;
@@ -0,0 +1,150 @@
+use std::env;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use camel_cards::{
+    card::Card,
+    hand::{Hand, HandType},
+    hand_data::HandData,
+    reference,
+    report::{RankingReport, ReportOrder},
+    ruleset::Ruleset,
+    simulator::{self, Distribution, Odds, Rng},
+};
+
+const TESTFILEPATH: &str = "./test_input.txt";
+const FILEPATH: &str = "./input.txt";
+
+fn main() {
+    let args = env::args().collect::<Vec<String>>();
+    let part = match get_arg_value(&args, "--part") {
+        Some(p) => p.parse::<u8>().unwrap(),
+        None => 1,
+    };
+    // --rules picks a ruleset by name and --rules-file reads one from a config file, either
+    // overriding the part's.
+    let ruleset = match (
+        get_arg_value(&args, "--rules"),
+        get_arg_value(&args, "--rules-file"),
+    ) {
+        (_, Some(path)) => Ruleset::parse(BufReader::new(open_file(path))).unwrap(),
+        (Some(name), None) => Ruleset::preset(name).unwrap(),
+        (None, None) => match part {
+            1 => Ruleset::part1(),
+            2 => Ruleset::part2(),
+            _ => panic!("Unknown part {}, expected 1 or 2.", part),
+        },
+    };
+
+    // --explain <hand> walks through how one hand is classified instead of scoring a file.
+    if let Some(hand) = get_arg_value(&args, "--explain") {
+        let hand = hand
+            .chars()
+            .map(|c| ruleset.parse_card(c))
+            .collect::<Result<Vec<Card>, String>>()
+            .unwrap();
+        print!("{}", reference::explain(&ruleset, &hand));
+        return;
+    }
+
+    // --simulate prints how often each hand type is dealt, next to the same deck with
+    // nothing wild, and --odds <hand> how that hand fares against --opponents N random
+    // hands. Every hand the deck can deal is counted, unless --samples N asks for that
+    // many random ones, dealt from --seed.
+    let samples = get_arg_value(&args, "--samples").map(|n| n.parse::<u64>().unwrap());
+    let mut rng = match get_arg_value(&args, "--seed") {
+        Some(seed) => Rng::new(seed.parse::<u64>().unwrap()),
+        None => Rng::new(1),
+    };
+    if args.contains(&"--simulate".to_string()) {
+        let mut rulesets = vec![ruleset.without_wild()];
+        if ruleset.has_wild() {
+            rulesets.push(ruleset);
+        }
+        let distributions = rulesets
+            .iter()
+            .map(|r| match samples {
+                Some(n) => Distribution::sample(r, n, &mut rng),
+                None => Distribution::enumerate(r),
+            })
+            .collect::<Result<Vec<Distribution>, String>>()
+            .unwrap();
+        print!("{}", simulator::format_table(&distributions));
+        return;
+    }
+    if let Some(cards) = get_arg_value(&args, "--odds") {
+        let opponents = match get_arg_value(&args, "--opponents") {
+            Some(n) => n.parse::<u64>().unwrap(),
+            None => 1,
+        };
+        let hand = Hand::parse(format!("{} 0", cards), &ruleset).unwrap();
+        let odds = match samples {
+            Some(n) => Odds::sample(&ruleset, &hand, n, &mut rng),
+            None => Odds::enumerate(&ruleset, &hand),
+        }
+        .unwrap();
+        println!(
+            "{} ({}) under ruleset {} beats {}, ties {} and loses to {} of {} hands.",
+            cards,
+            hand.get_hand_type(),
+            ruleset.name(),
+            odds.weaker,
+            odds.tied,
+            odds.stronger,
+            odds.hands()
+        );
+        println!(
+            "Expected rank among {} random opponents: {:.3} of {}",
+            opponents,
+            odds.expected_rank(opponents),
+            opponents + 1
+        );
+        return;
+    }
+
+    // --input reads hands from another file, such as one dealt under a --rules-file.
+    let is_test = args.contains(&"-t".to_string());
+    let file_path = match (get_arg_value(&args, "--input"), is_test) {
+        (Some(path), _) => path,
+        (None, true) => TESTFILEPATH,
+        (None, false) => FILEPATH,
+    };
+
+    let buf = BufReader::new(open_file(file_path));
+    let mut hand_data = HandData::parse(buf, &ruleset).unwrap();
+
+    // --report prints every hand's ranking, ordered by --sort rank|type|winnings and
+    // limited to one hand type by --type, e.g. --type full_house.
+    if args.contains(&"--report".to_string()) {
+        let mut report = RankingReport::new(&mut hand_data);
+        if let Some(name) = get_arg_value(&args, "--type") {
+            report.retain_type(HandType::parse(name).unwrap());
+        }
+        if let Some(name) = get_arg_value(&args, "--sort") {
+            report.sort(&ReportOrder::parse(name).unwrap());
+        }
+        print!("{}", report);
+        return;
+    }
+
+    let result = hand_data.calc_total_winnings();
+
+    println!("{}", result);
+}
+
+fn get_arg_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    let i = args.iter().position(|a| a == flag)?;
+    return args.get(i + 1).map(|a| a.as_str());
+}
+
+fn open_file<P>(path: P) -> File
+where
+    P: AsRef<Path> + std::fmt::Display,
+{
+    let file = std::fs::File::open(&path);
+    match file {
+        Ok(file) => file,
+        Err(e) => panic!("Could not open file {}: {}", path, e),
+    }
+}
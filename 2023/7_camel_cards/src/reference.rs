@@ -0,0 +1,173 @@
+use crate::{card::Card, hand::HandType, ruleset::Ruleset};
+
+/// The outcome of classifying a hand by trying every card in place of each wild card.
+pub struct BruteForce {
+    pub hand_type: HandType,
+    pub best: Vec<Card>,
+    pub tried: usize,
+}
+
+/// Reference classifier for `Ruleset::get_type`. Every wild card is replaced by each card
+/// in the deck in turn, and the hand takes the best type any substitution makes, with
+/// nothing wild. Slow, but there's nothing in it to get wrong.
+pub fn get_type_brute_force(ruleset: &Ruleset, hand: &[Card]) -> BruteForce {
+    let wild_positions = (0..hand.len())
+        .filter(|&i| ruleset.is_wild(&hand[i]))
+        .collect::<Vec<usize>>();
+
+    let mut result = BruteForce {
+        hand_type: classify_concrete(ruleset, hand),
+        best: hand.to_vec(),
+        tried: 0,
+    };
+    let mut substituted = hand.to_vec();
+    substitute(ruleset, &mut substituted, &wild_positions, &mut result);
+
+    return result;
+}
+
+fn substitute(
+    ruleset: &Ruleset,
+    hand: &mut Vec<Card>,
+    wild_positions: &[usize],
+    result: &mut BruteForce,
+) {
+    let Some((&position, rest)) = wild_positions.split_first() else {
+        let hand_type = classify_concrete(ruleset, hand);
+        if result.tried == 0 || hand_type > result.hand_type {
+            result.hand_type = hand_type;
+            result.best = hand.clone();
+        }
+        result.tried += 1;
+        return;
+    };
+
+    let original = hand[position];
+    for &card in ruleset.cards() {
+        hand[position] = card;
+        substitute(ruleset, hand, rest, result);
+    }
+    hand[position] = original;
+}
+
+/// Classifies `hand` taking every card at face value, ignoring which cards are wild.
+fn classify_concrete(ruleset: &Ruleset, hand: &[Card]) -> HandType {
+    let mut counts = vec![0u8; ruleset.cards().len()];
+    for c in hand.iter() {
+        counts[ruleset.get_rank(c) as usize] += 1;
+    }
+    let mut groups = counts.into_iter().filter(|&n| n > 0).collect::<Vec<u8>>();
+    groups.sort_by(|a, b| b.cmp(a));
+    return ruleset.classify(&groups);
+}
+
+/// Walks through how `hand` is classified under `ruleset`, first by the fast group count
+/// and then by the reference substitution search.
+pub fn explain(ruleset: &Ruleset, hand: &[Card]) -> String {
+    let hand_string = hand.iter().map(|c| c.to_char()).collect::<String>();
+    let mut result = format!("Hand {} under ruleset {}:\n", hand_string, ruleset.name());
+
+    let cards = hand
+        .iter()
+        .map(|c| match ruleset.is_wild(c) {
+            true => format!("{} (rank {}, wild)", c, ruleset.get_rank(c)),
+            false => format!("{} (rank {})", c, ruleset.get_rank(c)),
+        })
+        .collect::<Vec<String>>();
+    result = format!("{}\tCards: {}\n", result, cards.join(", "));
+
+    let (mut groups, wildcards) = ruleset.get_groups(hand);
+    result = format!(
+        "{}\tGroups of matching cards that aren't wild: {:?}\n",
+        result, groups
+    );
+    match groups.first_mut() {
+        Some(largest) => *largest += wildcards,
+        None => groups.push(wildcards),
+    }
+    result = format!(
+        "{}\t{} wild cards join the largest group: {:?}\n",
+        result, wildcards, groups
+    );
+    let fast = ruleset.get_type(hand);
+    result = format!("{}\tFast classification: {}\n", result, fast);
+
+    let reference = get_type_brute_force(ruleset, hand);
+    let best = reference
+        .best
+        .iter()
+        .map(|c| c.to_char())
+        .collect::<String>();
+    result = format!(
+        "{}\tReference: tried {} substitutions, best was {}: {}\n",
+        result, reference.tried, best, reference.hand_type
+    );
+
+    let verdict = match fast == reference.hand_type {
+        true => "Classifiers agree.",
+        false => "Classifiers DISAGREE!",
+    };
+    result = format!("{}\t{}\n", result, verdict);
+
+    return result;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::Card;
+    use crate::reference::{explain, get_type_brute_force};
+    use crate::ruleset::Ruleset;
+
+    /// Checks every five-card hand the deck can deal.
+    fn assert_all_hands_agree(ruleset: &Ruleset) {
+        let deck = ruleset.cards();
+        let mut hand = vec![deck[0]; 5];
+        for i in 0..deck.len().pow(5) {
+            let mut n = i;
+            for card in hand.iter_mut() {
+                *card = deck[n % deck.len()];
+                n /= deck.len();
+            }
+
+            let fast = ruleset.get_type(&hand);
+            let reference = get_type_brute_force(ruleset, &hand).hand_type;
+            assert!(
+                fast == reference,
+                "{:?} under {}: fast {}, reference {}",
+                hand,
+                ruleset.name(),
+                fast,
+                reference
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_type_matches_reference_part1() {
+        assert_all_hands_agree(&Ruleset::part1());
+    }
+
+    #[test]
+    fn test_get_type_matches_reference_part2() {
+        assert_all_hands_agree(&Ruleset::part2());
+    }
+
+    #[test]
+    fn test_explain() {
+        let hand = "KTJJT"
+            .chars()
+            .map(|c| Card::parse(c).unwrap())
+            .collect::<Vec<Card>>();
+        let explanation = explain(&Ruleset::part2(), &hand);
+        assert_eq!(
+            explanation,
+            "Hand KTJJT under ruleset part2:\n\
+             \tCards: K (rank 11), T (rank 9), J (rank 0, wild), J (rank 0, wild), T (rank 9)\n\
+             \tGroups of matching cards that aren't wild: [2, 1]\n\
+             \t2 wild cards join the largest group: [4, 1]\n\
+             \tFast classification: Four of a kind\n\
+             \tReference: tried 169 substitutions, best was KTTTT: Four of a kind\n\
+             \tClassifiers agree.\n"
+        );
+    }
+}
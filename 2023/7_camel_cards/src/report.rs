@@ -0,0 +1,204 @@
+use std::fmt::Display;
+
+use crate::{hand::HandType, hand_data::HandData};
+
+/// One line of the ranking report.
+pub struct RankedHand {
+    pub rank: u32,
+    pub cards: String,
+    pub hand_type: HandType,
+    /// Which card, counting from 1, put this hand above the next weaker hand of the same
+    /// type. `None` for the weakest hand of each type.
+    pub tie_break: Option<usize>,
+    pub bid: u32,
+    pub winnings: u32,
+}
+
+pub enum ReportOrder {
+    Rank,
+    Type,
+    Winnings,
+}
+impl ReportOrder {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        return match name {
+            "rank" => Ok(ReportOrder::Rank),
+            "type" => Ok(ReportOrder::Type),
+            "winnings" => Ok(ReportOrder::Winnings),
+            _ => Err(format!(
+                "Invalid report order {}, expected rank, type or winnings.",
+                name
+            )),
+        };
+    }
+}
+
+/// Every hand's rank, type, tie-break card and contribution to the total winnings.
+pub struct RankingReport(Vec<RankedHand>);
+impl RankingReport {
+    pub fn new(hand_data: &mut HandData) -> Self {
+        hand_data.sort_by_rank();
+        let hands = &hand_data.0;
+
+        let mut result = vec![];
+        for i in 0..hands.len() {
+            let hand = &hands[i];
+            let rank = (i + 1) as u32;
+
+            let tie_break = match i > 0 && hands[i - 1].get_hand_type() == hand.get_hand_type() {
+                true => {
+                    let (ranks, prev_ranks) = (hand.get_ranks(), hands[i - 1].get_ranks());
+                    (0..ranks.len().min(prev_ranks.len()))
+                        .find(|&j| ranks[j] != prev_ranks[j])
+                        .map(|j| j + 1)
+                }
+                false => None,
+            };
+
+            result.push(RankedHand {
+                rank,
+                cards: hand.get_hand().iter().map(|c| c.to_char()).collect(),
+                hand_type: hand.get_hand_type().clone(),
+                tie_break,
+                bid: hand.get_bid(),
+                winnings: hand.calc_winnings(rank),
+            });
+        }
+
+        return Self(result);
+    }
+
+    pub fn hands(&self) -> &Vec<RankedHand> {
+        return &self.0;
+    }
+
+    /// Strongest type first for `Type` and largest first for `Winnings`, falling back to
+    /// rank order.
+    pub fn sort(&mut self, order: &ReportOrder) {
+        match order {
+            ReportOrder::Rank => self.0.sort_by_key(|h| h.rank),
+            ReportOrder::Type => self
+                .0
+                .sort_by(|a, b| b.hand_type.cmp(&a.hand_type).then(a.rank.cmp(&b.rank))),
+            ReportOrder::Winnings => self
+                .0
+                .sort_by(|a, b| b.winnings.cmp(&a.winnings).then(a.rank.cmp(&b.rank))),
+        }
+    }
+
+    pub fn retain_type(&mut self, hand_type: HandType) {
+        self.0.retain(|h| h.hand_type == hand_type);
+    }
+
+    pub fn calc_total_winnings(&self) -> u32 {
+        return self.0.iter().map(|h| h.winnings).sum();
+    }
+}
+impl Display for RankingReport {
+    /// The Hand and Type columns are as wide as their longest entry, plus a gap.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let types = self
+            .0
+            .iter()
+            .map(|h| h.hand_type.to_string())
+            .collect::<Vec<String>>();
+        let type_width = types.iter().map(|t| t.len()).fold("Type".len(), usize::max) + 2;
+        let hand_width = self
+            .0
+            .iter()
+            .map(|h| h.cards.len())
+            .fold("Hand".len(), usize::max)
+            + 2;
+
+        let mut result = format!(
+            "{:>6}  {:<hand_width$}{:<type_width$}{:<11}{:>6}{:>10}\n",
+            "Rank", "Hand", "Type", "Tie-break", "Bid", "Winnings"
+        );
+        for (h, hand_type) in self.0.iter().zip(types) {
+            let tie_break = match h.tie_break {
+                Some(position) => format!("card {}", position),
+                None => "-".to_string(),
+            };
+            result.push_str(&format!(
+                "{:>6}  {:<hand_width$}{:<type_width$}{:<11}{:>6}{:>10}\n",
+                h.rank, h.cards, hand_type, tie_break, h.bid, h.winnings
+            ));
+        }
+        result.push_str(&format!("Total winnings: {}\n", self.calc_total_winnings()));
+        write!(f, "{}", result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hand::HandType;
+    use crate::hand_data::HandData;
+    use crate::report::{RankingReport, ReportOrder};
+    use crate::ruleset::Ruleset;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::path::Path;
+
+    pub fn open_file<P>(path: P) -> File
+    where
+        P: AsRef<Path> + std::fmt::Display,
+    {
+        let file = std::fs::File::open(&path);
+        match file {
+            Ok(file) => file,
+            Err(e) => panic!("Could not open file {}: {}", path, e),
+        }
+    }
+
+    #[test]
+    fn test_ranking_report() {
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        let mut hand_data = HandData::parse(buf, &Ruleset::part1()).unwrap();
+        let mut report = RankingReport::new(&mut hand_data);
+
+        let summary = |report: &RankingReport| {
+            report
+                .hands()
+                .iter()
+                .map(|h| (h.rank, h.cards.clone(), h.tie_break, h.winnings))
+                .collect::<Vec<(u32, String, Option<usize>, u32)>>()
+        };
+        assert_eq!(
+            summary(&report),
+            vec![
+                (1, "32T3K".to_string(), None, 765),
+                (2, "KTJJT".to_string(), None, 440),
+                (3, "KK677".to_string(), Some(2), 84),
+                (4, "T55J5".to_string(), None, 2736),
+                (5, "QQQJA".to_string(), Some(1), 2415),
+            ]
+        );
+        assert_eq!(report.calc_total_winnings(), 6440);
+
+        report.sort(&ReportOrder::Winnings);
+        assert_eq!(report.hands()[0].cards, "T55J5");
+
+        report.retain_type(HandType::parse("two_pair").unwrap());
+        report.sort(&ReportOrder::Type);
+        assert_eq!(
+            report.to_string(),
+            "  Rank  Hand   Type      Tie-break     Bid  Winnings\n     \
+             2  KTJJT  Two pair  -             220       440\n     \
+             3  KK677  Two pair  card 2         28        84\n\
+             Total winnings: 524\n"
+        );
+
+        // Six-card hands and the longer "Five of a kind" widen the Hand and Type columns.
+        let buf = BufReader::new(open_file("./test_input_six.txt"));
+        let ruleset = Ruleset::parse(BufReader::new(open_file("./test_rules_six.txt"))).unwrap();
+        let mut hand_data = HandData::parse(buf, &ruleset).unwrap();
+        let report = RankingReport::new(&mut hand_data).to_string();
+        assert_eq!(
+            report.lines().take(2).collect::<Vec<&str>>(),
+            vec![
+                "  Rank  Hand    Type            Tie-break     Bid  Winnings",
+                "     1  9TQKAJ  One pair        -              50        50",
+            ]
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use crate::{card::Card, hand::HandType};
+
+/// The usual Camel Cards deck in face order, from Two up to Ace.
+pub const STANDARD_DECK: &str = "23456789TJQKA";
+
+/// Maps the sizes of a hand's groups of matching cards, largest first and with any wild
+/// cards already added to the largest group, to the hand's type.
+pub type Classifier = fn(&[u8]) -> HandType;
+
+/// How a game of Camel Cards is scored: which cards make up the deck and beat which when
+/// breaking ties, which cards are wild, how many cards a hand holds, and how groups of
+/// matching cards make a hand type.
+pub struct Ruleset {
+    name: String,
+    order: Vec<Card>,
+    wild: Vec<Card>,
+    hand_size: Option<usize>,
+    classify: Classifier,
+}
+impl Ruleset {
+    /// `order` is the deck, listing every card from weakest to strongest, and `wild` the
+    /// cards that can stand in for any other when working out a hand's type. With a
+    /// `hand_size`, hands of any other length are rejected. Groups are counted in a `u8`,
+    /// so no hand can hold more than 255 cards.
+    pub fn new(
+        name: &str,
+        order: &str,
+        wild: &str,
+        hand_size: Option<usize>,
+        classify: Classifier,
+    ) -> Result<Self, String> {
+        let order = order
+            .chars()
+            .map(Card::parse)
+            .collect::<Result<Vec<Card>, String>>()?;
+        let wild = wild
+            .chars()
+            .map(Card::parse)
+            .collect::<Result<Vec<Card>, String>>()?;
+
+        if order.is_empty() {
+            return Err(format!("Ruleset {} has an empty deck.", name));
+        }
+        if order.len() > u8::MAX as usize + 1 {
+            return Err(format!(
+                "Ruleset {} has {} cards, but decks hold at most {}.",
+                name,
+                order.len(),
+                u8::MAX as usize + 1
+            ));
+        }
+        for (i, card) in order.iter().enumerate() {
+            if order[i + 1..order.len()].contains(card) {
+                return Err(format!("Ruleset {} ranks {} more than once.", name, card));
+            }
+        }
+        if let Some(card) = wild.iter().find(|c| !order.contains(c)) {
+            return Err(format!(
+                "Ruleset {} has a wild card {} that isn't in the deck.",
+                name, card
+            ));
+        }
+        if hand_size == Some(0) {
+            return Err(format!("Ruleset {} deals empty hands.", name));
+        }
+        if let Some(size) = hand_size.filter(|&n| n > u8::MAX as usize) {
+            return Err(format!(
+                "Ruleset {} deals hands of {} cards, but hands hold at most {}.",
+                name,
+                size,
+                u8::MAX
+            ));
+        }
+
+        return Ok(Self {
+            name: name.to_string(),
+            order,
+            wild,
+            hand_size,
+            classify,
+        });
+    }
+
+    /// Reads a ruleset from `key = value` lines, where `order` is required and `name`,
+    /// `wild` and `hand_size` are optional. Blank lines and lines starting with `#` are
+    /// skipped, for example:
+    ///
+    /// ```text
+    /// name = six_card
+    /// order = J9TQKA
+    /// wild = J
+    /// hand_size = 6
+    /// ```
+    pub fn parse(buf: BufReader<File>) -> Result<Self, String> {
+        let mut name = "custom".to_string();
+        let mut order = None;
+        let mut wild = "".to_string();
+        let mut hand_size = None;
+
+        for l in buf.lines() {
+            let l = match l {
+                Ok(line) => line,
+                Err(e) => return Err(e.to_string()),
+            };
+            let l = l.trim();
+            if l.is_empty() || l.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = match l.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => return Err(format!("Ruleset line is missing an '=': {}", l)),
+            };
+            match key {
+                "name" => name = value.to_string(),
+                "order" => order = Some(value.to_string()),
+                "wild" => wild = value.to_string(),
+                "hand_size" => match value.parse::<usize>() {
+                    Ok(n) => hand_size = Some(n),
+                    Err(e) => return Err(format!("Invalid hand_size {}: {}", value, e)),
+                },
+                _ => return Err(format!("Unknown ruleset key: {}", key)),
+            }
+        }
+
+        let order = match order {
+            Some(order) => order,
+            None => return Err(format!("Ruleset {} has no card order.", name)),
+        };
+        return Self::new(&name, &order, &wild, hand_size, classify_groups);
+    }
+
+    /// Jacks rank between Tens and Queens and nothing is wild.
+    pub fn part1() -> Self {
+        return Self::new("part1", STANDARD_DECK, "", Some(5), classify_groups).unwrap();
+    }
+
+    /// `J` is a wild Joker and the weakest card when breaking ties.
+    pub fn part2() -> Self {
+        return Self::new("part2", "J23456789TQKA", "J", Some(5), classify_groups).unwrap();
+    }
+
+    /// Jokers and Twos are both wild, and the weakest two cards when breaking ties.
+    pub fn two_wild() -> Self {
+        return Self::new("two_wild", "J23456789TQKA", "J2", Some(5), classify_groups).unwrap();
+    }
+
+    pub fn preset(name: &str) -> Result<Self, String> {
+        return match name {
+            "part1" => Ok(Self::part1()),
+            "part2" => Ok(Self::part2()),
+            "two_wild" => Ok(Self::two_wild()),
+            _ => Err(format!(
+                "Unknown ruleset {}, expected part1, part2 or two_wild.",
+                name
+            )),
+        };
+    }
+
+    /// The same deck, order and hand size, with nothing wild.
+    pub fn without_wild(&self) -> Self {
+        return Self {
+            name: format!("{}_no_wild", self.name),
+            order: self.order.clone(),
+            wild: Vec::new(),
+            hand_size: self.hand_size,
+            classify: self.classify,
+        };
+    }
+
+    pub fn name(&self) -> &str {
+        return &self.name;
+    }
+
+    /// Every card in the deck, weakest first.
+    pub fn cards(&self) -> &[Card] {
+        return &self.order;
+    }
+
+    pub fn hand_size(&self) -> Option<usize> {
+        return self.hand_size;
+    }
+
+    /// Parses `c` as a card, rejecting anything that isn't in this ruleset's deck.
+    pub fn parse_card(&self, c: char) -> Result<Card, String> {
+        let card = Card::parse(c)?;
+        if !self.order.contains(&card) {
+            return Err(format!(
+                "Card {} isn't in the deck for ruleset {}.",
+                card, self.name
+            ));
+        }
+        return Ok(card);
+    }
+
+    /// Where `card` sits in the ranking order, starting from 0 for the weakest card.
+    /// Panics if `card` isn't in the deck.
+    pub fn get_rank(&self, card: &Card) -> u8 {
+        return self.order.iter().position(|c| c == card).unwrap() as u8;
+    }
+
+    pub fn is_wild(&self, card: &Card) -> bool {
+        return self.wild.contains(card);
+    }
+
+    pub fn has_wild(&self) -> bool {
+        return !self.wild.is_empty();
+    }
+
+    pub fn get_type(&self, hand: &[Card]) -> HandType {
+        let (mut groups, wildcards) = self.get_groups(hand);
+
+        // Wild cards always do best joining the largest group.
+        match groups.first_mut() {
+            Some(largest) => *largest += wildcards,
+            None => groups.push(wildcards),
+        }
+
+        return self.classify(&groups);
+    }
+
+    /// Sizes of the groups of matching cards in `hand` that aren't wild, largest first,
+    /// along with how many wild cards it holds.
+    pub fn get_groups(&self, hand: &[Card]) -> (Vec<u8>, u8) {
+        let mut groups = vec![0u8; self.order.len()];
+        let mut wildcards = 0;
+        for c in hand.iter() {
+            match self.is_wild(c) {
+                true => wildcards += 1,
+                false => groups[self.get_rank(c) as usize] += 1,
+            }
+        }
+
+        groups.retain(|&n| n > 0);
+        groups.sort_by(|a, b| b.cmp(a));
+        return (groups, wildcards);
+    }
+
+    pub fn classify(&self, groups: &[u8]) -> HandType {
+        return (self.classify)(groups);
+    }
+}
+
+/// Ranks hands purely on their groups of matching cards, following the `HandType`
+/// ladder for any hand size.
+pub fn classify_groups(groups: &[u8]) -> HandType {
+    return HandType::new(groups);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::Card;
+    use crate::hand::{Hand, HandType};
+    use crate::ruleset::{classify_groups, Ruleset, STANDARD_DECK};
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::path::Path;
+
+    pub fn open_file<P>(path: P) -> File
+    where
+        P: AsRef<Path> + std::fmt::Display,
+    {
+        let file = std::fs::File::open(&path);
+        match file {
+            Ok(file) => file,
+            Err(e) => panic!("Could not open file {}: {}", path, e),
+        }
+    }
+
+    fn get_type(ruleset: &Ruleset, hand: &str) -> String {
+        let hand = hand
+            .chars()
+            .map(|c| ruleset.parse_card(c).unwrap())
+            .collect::<Vec<Card>>();
+        return ruleset.get_type(&hand).to_string();
+    }
+
+    #[test]
+    fn test_ruleset_presets() {
+        let (part1, part2, two_wild) = (Ruleset::part1(), Ruleset::part2(), Ruleset::two_wild());
+
+        assert_eq!(get_type(&part1, "KTJJT"), "Two pair");
+        assert_eq!(get_type(&part2, "KTJJT"), "Four of a kind");
+        assert_eq!(get_type(&part2, "JJJJJ"), "Five of a kind");
+        assert_eq!(get_type(&part2, "2345J"), "One pair");
+        assert_eq!(get_type(&two_wild, "2345J"), "Three of a kind");
+        assert_eq!(get_type(&two_wild, "22JJA"), "Five of a kind");
+
+        let (jack, ten) = (Card::parse('J').unwrap(), Card::parse('T').unwrap());
+        assert!(part1.get_rank(&jack) > part1.get_rank(&ten));
+        assert_eq!(part2.get_rank(&jack), 0);
+        assert_eq!(two_wild.get_rank(&Card::parse('2').unwrap()), 1);
+        assert!(part1.parse_card('X').is_err());
+    }
+
+    #[test]
+    fn test_ruleset_new_errors() {
+        let new =
+            |order: &str, wild: &str| Ruleset::new("test", order, wild, None, classify_groups);
+        assert!(new("", "").is_err());
+        assert!(new("22456789TJQKA", "").is_err());
+        assert!(new(STANDARD_DECK, "X").is_err());
+        assert!(new("XYZ", "Z").is_ok());
+        assert!(Ruleset::new("test", "XYZ", "", Some(255), classify_groups).is_ok());
+        assert!(Ruleset::new("test", "XYZ", "", Some(256), classify_groups).is_err());
+        assert!(Ruleset::preset("nope").is_err());
+    }
+
+    #[test]
+    fn test_ruleset_hand_errors() {
+        let ruleset = Ruleset::new("test", "XYZ", "", None, classify_groups).unwrap();
+        let card = |c: char| Card::parse(c).unwrap();
+
+        // Cards from outside the deck can't be ranked, and groups of more than 255
+        // matching cards can't be counted.
+        assert!(Hand::new(vec![card('X'), card('A')], 1, &ruleset).is_err());
+        assert!(Hand::new(vec![card('X'); 255], 1, &ruleset).is_ok());
+        assert!(Hand::new(vec![card('X'); 256], 1, &ruleset).is_err());
+        assert!(Hand::new(vec![card('A'); 5], 1, &Ruleset::part1()).is_ok());
+        assert!(Hand::new(vec![card('X'); 5], 1, &Ruleset::part1()).is_err());
+    }
+
+    #[test]
+    fn test_ruleset_parse_six_card() {
+        let buf = BufReader::new(open_file("./test_rules_six.txt"));
+        let ruleset = Ruleset::parse(buf).unwrap();
+        assert_eq!(ruleset.name(), "six_card");
+        assert_eq!(ruleset.cards().len(), 6);
+        assert_eq!(ruleset.hand_size(), Some(6));
+
+        assert_eq!(get_type(&ruleset, "AAAAAA"), "Six of a kind");
+        assert_eq!(get_type(&ruleset, "99TTQQ"), "Three pairs");
+        assert_eq!(get_type(&ruleset, "AAAAKK"), "Four of a kind and a pair");
+        assert!(HandType::parse("three_pairs").unwrap() < HandType::parse("full_house").unwrap());
+        assert!(HandType::parse("4+2").unwrap() > HandType::parse("four_kind").unwrap());
+    }
+}
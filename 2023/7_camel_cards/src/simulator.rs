@@ -0,0 +1,391 @@
+use std::collections::BTreeMap;
+
+use crate::{card::Card, hand::Hand, hand::HandType, ruleset::Ruleset};
+
+/// Hands are dealt this many cards when the ruleset doesn't say.
+pub const DEFAULT_HAND_SIZE: usize = 5;
+
+/// Enumerating more hands than this takes too long, so sample instead.
+pub const ENUMERATION_LIMIT: u64 = 100_000_000;
+
+/// A small xorshift generator, so sampled runs repeat for the same seed.
+pub struct Rng(u64);
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // xorshift never leaves zero, so mix the seed into a non-zero state.
+        return match seed ^ 0x9E37_79B9_7F4A_7C15 {
+            0 => Self(1),
+            state => Self(state),
+        };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        return self.0;
+    }
+
+    /// A number from 0 up to but not including `n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        return ((self.next_u64() as u128 * n as u128) >> 64) as usize;
+    }
+}
+
+/// How many hands of each type a ruleset deals, either counted over every hand the deck
+/// can make or estimated from a sample.
+pub struct Distribution {
+    ruleset: String,
+    sampled: bool,
+    hands: u64,
+    counts: BTreeMap<HandType, u64>,
+}
+impl Distribution {
+    /// Counts the type of every hand the deck can deal.
+    pub fn enumerate(ruleset: &Ruleset) -> Result<Self, String> {
+        let hand_size = get_hand_size(ruleset);
+        check_enumerable(ruleset, hand_size)?;
+
+        let mut distribution = Self::new(ruleset, false);
+        for_each_hand(ruleset.cards(), hand_size, |hand| {
+            distribution.add(ruleset.get_type(hand));
+        });
+        return Ok(distribution);
+    }
+
+    /// Counts the types of `samples` hands dealt at random, each card drawn independently
+    /// from the whole deck.
+    pub fn sample(ruleset: &Ruleset, samples: u64, rng: &mut Rng) -> Result<Self, String> {
+        if samples == 0 {
+            return Err("Can't sample zero hands.".to_string());
+        }
+
+        let hand_size = get_hand_size(ruleset);
+        let mut distribution = Self::new(ruleset, true);
+        let mut hand = Vec::with_capacity(hand_size);
+        for _ in 0..samples {
+            deal(ruleset.cards(), hand_size, rng, &mut hand);
+            distribution.add(ruleset.get_type(&hand));
+        }
+        return Ok(distribution);
+    }
+
+    fn new(ruleset: &Ruleset, sampled: bool) -> Self {
+        return Self {
+            ruleset: ruleset.name().to_string(),
+            sampled,
+            hands: 0,
+            counts: BTreeMap::new(),
+        };
+    }
+
+    fn add(&mut self, hand_type: HandType) {
+        *self.counts.entry(hand_type).or_insert(0) += 1;
+        self.hands += 1;
+    }
+
+    pub fn ruleset(&self) -> &str {
+        return &self.ruleset;
+    }
+
+    pub fn is_sampled(&self) -> bool {
+        return self.sampled;
+    }
+
+    pub fn hands(&self) -> u64 {
+        return self.hands;
+    }
+
+    pub fn count(&self, hand_type: &HandType) -> u64 {
+        return *self.counts.get(hand_type).unwrap_or(&0);
+    }
+
+    pub fn frequency(&self, hand_type: &HandType) -> f64 {
+        return self.count(hand_type) as f64 / self.hands as f64;
+    }
+
+    /// Every type dealt at least once, strongest first.
+    pub fn hand_types(&self) -> Vec<&HandType> {
+        return self.counts.keys().rev().collect();
+    }
+}
+impl std::fmt::Display for Distribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format_table(std::slice::from_ref(self)))
+    }
+}
+
+/// Lays out distributions side by side, one row per hand type from strongest to weakest,
+/// with each type's count and share of the hands dealt.
+pub fn format_table(distributions: &[Distribution]) -> String {
+    let mut hand_types = distributions
+        .iter()
+        .flat_map(|d| d.counts.keys())
+        .collect::<Vec<&HandType>>();
+    hand_types.sort_by(|a, b| b.cmp(a));
+    hand_types.dedup();
+    let width = hand_types
+        .iter()
+        .map(|t| t.to_string().len())
+        .chain(std::iter::once("Type".len()))
+        .max()
+        .unwrap()
+        + 2;
+
+    let mut result = format!("{:<width$}", "Type");
+    for d in distributions.iter() {
+        let name = match d.sampled {
+            true => format!("{} (sampled)", d.ruleset),
+            false => d.ruleset.clone(),
+        };
+        result = format!("{}{:>24}", result, name);
+    }
+    result = format!("{}\n", result);
+
+    for hand_type in hand_types.iter() {
+        result = format!("{}{:<width$}", result, hand_type.to_string());
+        for d in distributions.iter() {
+            result = format!(
+                "{}{:>14}{:>9.4}%",
+                result,
+                d.count(hand_type),
+                d.frequency(hand_type) * 100.0
+            );
+        }
+        result = format!("{}\n", result);
+    }
+
+    result = format!("{}{:<width$}", result, "Hands");
+    for d in distributions.iter() {
+        result = format!("{}{:>14}{:>10}", result, d.hands, "");
+    }
+    return format!("{}\n", result.trim_end());
+}
+
+/// How one hand fares against the hands the deck deals.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Odds {
+    pub weaker: u64,
+    pub tied: u64,
+    pub stronger: u64,
+}
+impl Odds {
+    /// Compares `hand` against every hand of the same size the deck can deal.
+    pub fn enumerate(ruleset: &Ruleset, hand: &Hand) -> Result<Self, String> {
+        let hand_size = hand.get_hand().len();
+        check_enumerable(ruleset, hand_size)?;
+
+        let mut odds = Self::new();
+        let mut result = Ok(());
+        for_each_hand(ruleset.cards(), hand_size, |other| {
+            match Hand::new(other.to_vec(), 0, ruleset) {
+                Ok(other) => odds.add(&other, hand),
+                Err(e) => result = Err(e),
+            }
+        });
+        result?;
+        return Ok(odds);
+    }
+
+    /// Compares `hand` against `samples` hands of the same size dealt at random.
+    pub fn sample(
+        ruleset: &Ruleset,
+        hand: &Hand,
+        samples: u64,
+        rng: &mut Rng,
+    ) -> Result<Self, String> {
+        if samples == 0 {
+            return Err("Can't sample zero hands.".to_string());
+        }
+
+        let mut odds = Self::new();
+        let mut other = Vec::with_capacity(hand.get_hand().len());
+        for _ in 0..samples {
+            deal(ruleset.cards(), hand.get_hand().len(), rng, &mut other);
+            odds.add(&Hand::new(other.clone(), 0, ruleset)?, hand);
+        }
+        return Ok(odds);
+    }
+
+    fn new() -> Self {
+        return Self {
+            weaker: 0,
+            tied: 0,
+            stronger: 0,
+        };
+    }
+
+    fn add(&mut self, other: &Hand, hand: &Hand) {
+        match other.cmp(hand) {
+            std::cmp::Ordering::Less => self.weaker += 1,
+            std::cmp::Ordering::Equal => self.tied += 1,
+            std::cmp::Ordering::Greater => self.stronger += 1,
+        }
+    }
+
+    pub fn hands(&self) -> u64 {
+        return self.weaker + self.tied + self.stronger;
+    }
+
+    /// The rank the hand can expect when played against `opponents` hands dealt at random,
+    /// from 1 for the weakest up to `opponents + 1` for the strongest. An opponent holding
+    /// the very same cards is equally likely to be ranked either side of it.
+    pub fn expected_rank(&self, opponents: u64) -> f64 {
+        let beaten = self.weaker as f64 + self.tied as f64 / 2.0;
+        return 1.0 + opponents as f64 * beaten / self.hands() as f64;
+    }
+}
+
+fn get_hand_size(ruleset: &Ruleset) -> usize {
+    return ruleset.hand_size().unwrap_or(DEFAULT_HAND_SIZE);
+}
+
+fn check_enumerable(ruleset: &Ruleset, hand_size: usize) -> Result<(), String> {
+    let hands = (ruleset.cards().len() as u64).checked_pow(hand_size as u32);
+    return match hands {
+        Some(n) if n <= ENUMERATION_LIMIT => Ok(()),
+        _ => Err(format!(
+            "Ruleset {} deals more than {} hands of {} cards, sample them instead.",
+            ruleset.name(),
+            ENUMERATION_LIMIT,
+            hand_size
+        )),
+    };
+}
+
+/// Calls `f` with every hand of `hand_size` cards drawn from `deck`, in order, counting
+/// through the deck like an odometer.
+fn for_each_hand<F>(deck: &[Card], hand_size: usize, mut f: F)
+where
+    F: FnMut(&[Card]),
+{
+    let mut positions = vec![0; hand_size];
+    let mut hand = vec![deck[0]; hand_size];
+    loop {
+        f(&hand);
+
+        let mut i = hand_size;
+        loop {
+            if i == 0 {
+                return;
+            }
+            i -= 1;
+            positions[i] += 1;
+            if positions[i] < deck.len() {
+                hand[i] = deck[positions[i]];
+                break;
+            }
+            positions[i] = 0;
+            hand[i] = deck[0];
+        }
+    }
+}
+
+fn deal(deck: &[Card], hand_size: usize, rng: &mut Rng, hand: &mut Vec<Card>) {
+    hand.clear();
+    for _ in 0..hand_size {
+        hand.push(deck[rng.below(deck.len())]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::Card;
+    use crate::hand::{Hand, HandType};
+    use crate::ruleset::Ruleset;
+    use crate::simulator::{format_table, Distribution, Odds, Rng};
+
+    fn hand_type(name: &str) -> HandType {
+        return HandType::parse(name).unwrap();
+    }
+
+    fn hand(ruleset: &Ruleset, cards: &str) -> Hand {
+        let cards = cards
+            .chars()
+            .map(|c| ruleset.parse_card(c).unwrap())
+            .collect::<Vec<Card>>();
+        return Hand::new(cards, 0, ruleset).unwrap();
+    }
+
+    #[test]
+    fn test_enumerate_distribution() {
+        let part1 = Distribution::enumerate(&Ruleset::part1()).unwrap();
+        assert_eq!(part1.hands(), 371293);
+        let expected = [
+            ("five_kind", 13),
+            ("four_kind", 780),
+            ("full_house", 1560),
+            ("three_kind", 17160),
+            ("two_pair", 25740),
+            ("one_pair", 171600),
+            ("high", 154440),
+        ];
+        for (name, count) in expected {
+            assert_eq!(part1.count(&hand_type(name)), count, "{}", name);
+        }
+        assert_eq!(part1.hand_types().len(), expected.len());
+
+        // Five of a kind with jokers: five jokers, or any other card with some jokers.
+        let part2 = Distribution::enumerate(&Ruleset::part2()).unwrap();
+        assert_eq!(part2.hands(), 371293);
+        assert_eq!(part2.count(&hand_type("five_kind")), 1 + 12 * 31);
+        assert!(part2.count(&hand_type("high")) < part1.count(&hand_type("high")));
+
+        let no_wild = Distribution::enumerate(&Ruleset::part2().without_wild()).unwrap();
+        assert_eq!(no_wild.count(&hand_type("five_kind")), 13);
+
+        let table = format_table(&[no_wild, part2]);
+        let lines = table.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 9);
+        assert!(lines[0].ends_with("part2_no_wild                   part2"));
+        assert!(lines[1].starts_with("Five of a kind"));
+        assert!(lines[1].contains("    13   0.0035%"));
+        assert!(lines[8].starts_with("Hands"));
+    }
+
+    #[test]
+    fn test_sample_distribution() {
+        let ruleset = Ruleset::part1();
+        let first = Distribution::sample(&ruleset, 20000, &mut Rng::new(7));
+        let second = Distribution::sample(&ruleset, 20000, &mut Rng::new(7));
+        let (first, second) = (first.unwrap(), second.unwrap());
+        assert_eq!(first.hands(), 20000);
+        assert!(first.is_sampled());
+        for hand_type in first.hand_types() {
+            assert_eq!(first.count(hand_type), second.count(hand_type));
+        }
+
+        // One pair turns up in about 46% of hands.
+        let one_pair = first.frequency(&hand_type("one_pair"));
+        assert!((0.44..0.48).contains(&one_pair), "{}", one_pair);
+        assert!(Distribution::sample(&ruleset, 0, &mut Rng::new(7)).is_err());
+    }
+
+    #[test]
+    fn test_odds() {
+        let ruleset = Ruleset::part1();
+        let best = Odds::enumerate(&ruleset, &hand(&ruleset, "AAAAA")).unwrap();
+        assert_eq!(
+            best,
+            Odds {
+                weaker: 371292,
+                tied: 1,
+                stronger: 0
+            }
+        );
+        let worst = Odds::enumerate(&ruleset, &hand(&ruleset, "23456")).unwrap();
+        assert_eq!(worst.stronger, 371292);
+        assert!((worst.expected_rank(10) - (1.0 + 5.0 / 371293.0)).abs() < 1e-12);
+        assert!((best.expected_rank(10) - (11.0 - 5.0 / 371293.0)).abs() < 1e-12);
+
+        // Every five of a kind beats KK677, and most hands are weaker than a pair of kings.
+        let kings = Odds::enumerate(&ruleset, &hand(&ruleset, "KK677")).unwrap();
+        assert!(kings.stronger > 13);
+        assert!(kings.expected_rank(1) > 1.5);
+
+        let sampled = Odds::sample(&ruleset, &hand(&ruleset, "KK677"), 20000, &mut Rng::new(3));
+        let sampled = sampled.unwrap();
+        assert_eq!(sampled.hands(), 20000);
+        assert!((sampled.expected_rank(1) - kings.expected_rank(1)).abs() < 0.02);
+    }
+}
@@ -1,3 +0,0 @@
-pub mod coord;
-pub mod location;
-pub mod map;
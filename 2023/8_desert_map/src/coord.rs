@@ -0,0 +1,21 @@
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord(String);
+impl Coord {
+    pub fn parse(set: String) -> Result<Self, String> {
+        let set = set.trim().to_string();
+        if set.is_empty() || !set.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(format!("String is an invalid Coordinate: {}", set));
+        }
+
+        return Ok(Coord(set));
+    }
+
+    pub fn val(&self) -> &str {
+        return &self.0;
+    }
+}
+impl std::fmt::Display for Coord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
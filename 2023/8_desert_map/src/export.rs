@@ -0,0 +1,168 @@
+use crate::{location::Locations, map::Map, matcher::Traversal};
+use std::{collections::HashSet, io::Write};
+
+pub struct TraceStep {
+    pub step: usize,
+    pub command_index: usize,
+    pub node: usize,
+}
+
+/// The nodes one ghost visits over its first steps, starting with where it begins.
+pub struct PathTrace(Vec<TraceStep>);
+impl PathTrace {
+    pub fn new(steps: Vec<TraceStep>) -> Self {
+        return Self(steps);
+    }
+
+    pub fn steps(&self) -> &Vec<TraceStep> {
+        return &self.0;
+    }
+
+    pub fn write_csv<W: Write>(&self, locations: &Locations, w: &mut W) -> Result<(), String> {
+        let mut write_line = |l: String| -> Result<(), String> {
+            return match writeln!(w, "{}", l) {
+                Ok(()) => Ok(()),
+                Err(e) => Err(e.to_string()),
+            };
+        };
+
+        write_line("step,instruction_index,node".to_string())?;
+        for s in self.0.iter() {
+            write_line(format!(
+                "{},{},{}",
+                s.step,
+                s.command_index,
+                locations.get_name(s.node)
+            ))?;
+        }
+        return Ok(());
+    }
+}
+
+/// Renders the L/R network as a Graphviz digraph. Nodes matching the traversal's start are
+/// filled green and its end red; if a trace is given, the nodes and edges it passes through
+/// are drawn in blue.
+pub fn to_dot(map: &Map, traversal: &Traversal, trace: Option<&PathTrace>) -> String {
+    let locations = map.locations();
+
+    let mut visited_nodes = HashSet::new();
+    let mut visited_edges = HashSet::new();
+    if let Some(trace) = trace {
+        let steps = trace.steps();
+        for (i, s) in steps.iter().enumerate() {
+            visited_nodes.insert(s.node);
+            // The last node is reached, but its next edge is never taken.
+            if i + 1 < steps.len() {
+                visited_edges.insert((s.node, map.steps()[s.command_index]));
+            }
+        }
+    }
+
+    let mut result = "digraph desert_map {\n".to_string();
+    for id in 0..locations.len() {
+        let name = locations.get_name(id);
+        let mut attrs = vec![];
+        if traversal.start.is_match(name) {
+            attrs.push("style=filled, fillcolor=palegreen".to_string());
+        } else if traversal.end.is_match(name) {
+            attrs.push("style=filled, fillcolor=lightcoral".to_string());
+        }
+        if visited_nodes.contains(&id) {
+            attrs.push("color=blue, penwidth=2".to_string());
+        }
+        match attrs.is_empty() {
+            true => result = format!("{}\t\"{}\";\n", result, name),
+            false => result = format!("{}\t\"{}\" [{}];\n", result, name, attrs.join(", ")),
+        }
+    }
+
+    let format_edge = |from: usize, to: usize, label: &str, is_visited: bool| -> String {
+        let highlight = if is_visited {
+            ", color=blue, penwidth=2"
+        } else {
+            ""
+        };
+        return format!(
+            "\t\"{}\" -> \"{}\" [label=\"{}\"{}];\n",
+            locations.get_name(from),
+            locations.get_name(to),
+            label,
+            highlight
+        );
+    };
+    for id in 0..locations.len() {
+        let (left, right) = (locations.get_left(id), locations.get_right(id));
+        let (left_visited, right_visited) = (
+            visited_edges.contains(&(id, false)),
+            visited_edges.contains(&(id, true)),
+        );
+        if left == right {
+            let edge = format_edge(id, left, "L/R", left_visited || right_visited);
+            result.push_str(&edge);
+            continue;
+        }
+        result.push_str(&format_edge(id, left, "L", left_visited));
+        result.push_str(&format_edge(id, right, "R", right_visited));
+    }
+    result.push_str("}\n");
+
+    return result;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::coord::Coord;
+    use crate::export::to_dot;
+    use crate::map::Map;
+    use crate::matcher::Traversal;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::path::Path;
+
+    pub fn open_file<P>(path: P) -> File
+    where
+        P: AsRef<Path> + std::fmt::Display,
+    {
+        let file = std::fs::File::open(&path);
+        match file {
+            Ok(file) => file,
+            Err(e) => panic!("Could not open file {}: {}", path, e),
+        }
+    }
+
+    #[test]
+    fn test_path_trace_csv() {
+        let buf = BufReader::new(open_file("./test_input_2.txt"));
+        let map = Map::parse(buf).unwrap();
+        let start = Coord::parse("22A".to_string()).unwrap();
+        let trace = map.get_path_trace(&start, 3).unwrap();
+
+        let mut csv = vec![];
+        trace.write_csv(map.locations(), &mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "step,instruction_index,node\n0,0,22A\n1,1,22B\n2,0,22C\n3,1,22Z\n"
+        );
+        assert!(map
+            .get_path_trace(&Coord::parse("NOPE".to_string()).unwrap(), 3)
+            .is_err());
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let buf = BufReader::new(open_file("./test_input_2.txt"));
+        let map = Map::parse(buf).unwrap();
+        let start = Coord::parse("11A".to_string()).unwrap();
+        let trace = map.get_path_trace(&start, 2).unwrap();
+        let dot = to_dot(&map, &Traversal::part2(), Some(&trace));
+
+        assert!(dot.starts_with("digraph desert_map {\n"));
+        assert!(dot
+            .contains("\t\"11A\" [style=filled, fillcolor=palegreen, color=blue, penwidth=2];\n"));
+        assert!(dot.contains("\t\"22Z\" [style=filled, fillcolor=lightcoral];\n"));
+        assert!(dot.contains("\t\"11A\" -> \"11B\" [label=\"L\", color=blue, penwidth=2];\n"));
+        assert!(dot.contains("\t\"11B\" -> \"11Z\" [label=\"R\", color=blue, penwidth=2];\n"));
+        assert!(dot.contains("\t\"11Z\" -> \"11B\" [label=\"L\"];\n"));
+        assert!(dot.contains("\t\"22B\" -> \"22C\" [label=\"L/R\"];\n"));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::coord::Coord;
+use aoc_utils::math;
+use std::fmt::Display;
+
+/// The route one ghost takes from its start, keyed on (node, instruction index): `tail`
+/// steps lead into a loop of `length` steps that then repeats forever.
+pub struct GhostCycle {
+    start: Coord,
+    tail: usize,
+    length: usize,
+    tail_ends: Vec<usize>,
+    cycle_ends: Vec<usize>,
+}
+impl GhostCycle {
+    /// `tail_ends` are the steps before the loop starts that land on an end node, and
+    /// `cycle_ends` are the steps within the first pass of the loop that do.
+    pub fn new(
+        start: Coord,
+        tail: usize,
+        length: usize,
+        tail_ends: Vec<usize>,
+        cycle_ends: Vec<usize>,
+    ) -> Self {
+        return Self {
+            start,
+            tail,
+            length,
+            tail_ends,
+            cycle_ends,
+        };
+    }
+
+    pub fn start(&self) -> &Coord {
+        return &self.start;
+    }
+
+    pub fn tail(&self) -> usize {
+        return self.tail;
+    }
+
+    pub fn length(&self) -> usize {
+        return self.length;
+    }
+
+    pub fn tail_ends(&self) -> &Vec<usize> {
+        return &self.tail_ends;
+    }
+
+    pub fn cycle_ends(&self) -> &Vec<usize> {
+        return &self.cycle_ends;
+    }
+
+    pub fn is_end_at(&self, step: u64) -> bool {
+        let tail = self.tail as u64;
+        if step < tail {
+            return self.tail_ends.contains(&(step as usize));
+        }
+        let cycle_step = tail + (step - tail) % self.length as u64;
+        return self.cycle_ends.contains(&(cycle_step as usize));
+    }
+
+    /// The first step at which every ghost is on an end node at the same time.
+    pub fn find_first_common_end(cycles: &[GhostCycle]) -> Result<Option<u64>, String> {
+        if cycles.is_empty() {
+            return Ok(None);
+        }
+
+        // Before every ghost has entered its loop, the only option is to check each step.
+        let max_tail = cycles.iter().map(|c| c.tail).max().unwrap() as u64;
+        for step in 0..max_tail {
+            if cycles.iter().all(|c| c.is_end_at(step)) {
+                return Ok(Some(step));
+            }
+        }
+
+        // From then on each ghost is periodic, so every combination of end offsets is a
+        // system of congruences.
+        let mut candidates: Vec<(i128, i128)> = vec![(0, 1)];
+        for cycle in cycles.iter() {
+            let mut next_candidates = vec![];
+            for &(residue, modulus) in candidates.iter() {
+                for &end in cycle.cycle_ends.iter() {
+                    let congruences = [(residue, modulus), (end as i128, cycle.length as i128)];
+                    if let Some(c) = math::crt(&congruences) {
+                        next_candidates.push(c);
+                        continue;
+                    }
+                    // Congruences that agree on their shared factor only fail by overflowing.
+                    let g = math::extended_gcd(modulus, cycle.length as i128).0;
+                    if (end as i128 - residue) % g == 0 {
+                        return Err(format!(
+                            "Combining cycle length {} with {} overflowed.",
+                            cycle.length, modulus
+                        ));
+                    }
+                }
+            }
+            next_candidates.sort();
+            next_candidates.dedup();
+            candidates = next_candidates;
+        }
+
+        let mut result = None;
+        for (residue, modulus) in candidates {
+            let max_tail = max_tail as i128;
+            let mut step = residue;
+            if step < max_tail {
+                step += (max_tail - step + modulus - 1) / modulus * modulus;
+            }
+            let step = match math::to_u64(step as u128) {
+                Some(s) => s,
+                None => continue,
+            };
+            result = match result {
+                Some(r) if r <= step => Some(r),
+                _ => Some(step),
+            };
+        }
+
+        return Ok(result);
+    }
+}
+impl Display for GhostCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Ghost from {}: tail {}, cycle length {}, ends in tail: {:?}, ends in cycle: {:?}",
+            self.start, self.tail, self.length, self.tail_ends, self.cycle_ends
+        )
+    }
+}
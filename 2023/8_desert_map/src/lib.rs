@@ -0,0 +1,6 @@
+pub mod coord;
+pub mod export;
+pub mod ghost_cycle;
+pub mod location;
+pub mod map;
+pub mod matcher;
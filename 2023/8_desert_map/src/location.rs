@@ -0,0 +1,146 @@
+use crate::coord::Coord;
+use std::{collections::HashMap, fmt::Display};
+
+const UNDEFINED: usize = usize::MAX;
+
+/// Every location name interned to a dense id, with the left and right fork of each id
+/// stored alongside so a step is a single index lookup.
+pub struct Locations {
+    names: Vec<Coord>,
+    ids: HashMap<Coord, usize>,
+    left: Vec<usize>,
+    right: Vec<usize>,
+}
+impl Locations {
+    pub fn new() -> Self {
+        return Self {
+            names: vec![],
+            ids: HashMap::new(),
+            left: vec![],
+            right: vec![],
+        };
+    }
+
+    /// Returns the id for `name`, assigning the next free id if it hasn't been seen yet.
+    pub fn intern(&mut self, name: Coord) -> usize {
+        if let Some(&id) = self.ids.get(&name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.ids.insert(name.clone(), id);
+        self.names.push(name);
+        self.left.push(UNDEFINED);
+        self.right.push(UNDEFINED);
+        return id;
+    }
+
+    pub fn insert(&mut self, pos: Coord, fork: (Coord, Coord)) -> Result<usize, String> {
+        let id = self.intern(pos);
+        if self.left[id] != UNDEFINED {
+            return Err(format!("Location {} is defined twice.", self.names[id]));
+        }
+        self.left[id] = self.intern(fork.0);
+        self.right[id] = self.intern(fork.1);
+        return Ok(id);
+    }
+
+    /// Checks every location that's referred to by a fork also has forks of its own.
+    pub fn validate(&self) -> Result<(), String> {
+        for id in 0..self.names.len() {
+            if self.left[id] == UNDEFINED {
+                return Err(format!("Could not find position {}", self.names[id]));
+            }
+        }
+        return Ok(());
+    }
+
+    pub fn len(&self) -> usize {
+        return self.names.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.names.is_empty();
+    }
+
+    pub fn get_id(&self, name: &Coord) -> Option<usize> {
+        return self.ids.get(name).copied();
+    }
+
+    pub fn get_name(&self, id: usize) -> &Coord {
+        return &self.names[id];
+    }
+
+    pub fn get_left(&self, id: usize) -> usize {
+        return self.left[id];
+    }
+
+    pub fn get_right(&self, id: usize) -> usize {
+        return self.right[id];
+    }
+}
+impl Default for Locations {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+impl Display for Locations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ids = (0..self.names.len()).collect::<Vec<usize>>();
+        ids.sort_by(|a, b| self.names[*a].cmp(&self.names[*b]));
+
+        let mut result = "Locations:\n".to_string();
+        for id in ids {
+            let fork = match self.left[id] {
+                UNDEFINED => "undefined".to_string(),
+                _ => format!(
+                    "({}, {})",
+                    self.names[self.left[id]], self.names[self.right[id]]
+                ),
+            };
+            result = format!("{}\tpos: {}, fork: {}\n", result, self.names[id], fork);
+        }
+        write!(f, "{}", result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::coord::Coord;
+    use crate::location::Locations;
+
+    fn coord(s: &str) -> Coord {
+        return Coord::parse(s.to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_locations_intern() {
+        let mut locations = Locations::new();
+        let start = locations
+            .insert(coord("START"), (coord("LONGER1"), coord("Z")))
+            .unwrap();
+        assert_eq!(start, 0);
+        assert_eq!(locations.len(), 3);
+        assert_eq!(
+            locations.get_name(locations.get_left(start)).val(),
+            "LONGER1"
+        );
+        assert_eq!(locations.get_id(&coord("Z")), Some(2));
+        // A name smaller than every entry is simply missing.
+        assert_eq!(locations.get_id(&coord("0")), None);
+
+        assert_eq!(
+            locations.validate(),
+            Err("Could not find position LONGER1".to_string())
+        );
+        locations
+            .insert(coord("LONGER1"), (coord("Z"), coord("Z")))
+            .unwrap();
+        locations
+            .insert(coord("Z"), (coord("Z"), coord("Z")))
+            .unwrap();
+        assert_eq!(locations.validate(), Ok(()));
+        assert!(locations
+            .insert(coord("Z"), (coord("Z"), coord("Z")))
+            .is_err());
+    }
+}
@@ -0,0 +1,100 @@
+use std::env;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use desert_map::{
+    coord::Coord,
+    export,
+    map::Map,
+    matcher::{NodeMatcher, Traversal},
+};
+
+const FILEPATH: &str = "./input.txt";
+const DEFAULTTRACESTEPS: usize = 100;
+
+fn main() {
+    let args = env::args().collect::<Vec<String>>();
+    let part = match get_arg_value(&args, "--part") {
+        Some(p) => p.parse::<u8>().unwrap(),
+        None => 1,
+    };
+    let is_test = args.contains(&"-t".to_string());
+    let test_file_path = format!("./test_input_{}.txt", part);
+    let file_path = match is_test {
+        true => test_file_path.as_str(),
+        false => FILEPATH,
+    };
+    let debug_print = args.contains(&"-d".to_string());
+
+    // --start and --end take a name (AAA), prefix (A*), suffix (*A) or list (AAA,BBB) and
+    // override the part's default.
+    let mut traversal = match part {
+        1 => Traversal::part1(),
+        2 => Traversal::part2(),
+        _ => panic!("Unknown part {}, expected 1 or 2.", part),
+    };
+    if let Some(pattern) = get_arg_value(&args, "--start") {
+        traversal.start = NodeMatcher::parse(pattern).unwrap();
+    }
+    if let Some(pattern) = get_arg_value(&args, "--end") {
+        traversal.end = NodeMatcher::parse(pattern).unwrap();
+    }
+
+    let buf = BufReader::new(open_file(file_path));
+    let map_data = Map::parse(buf).unwrap();
+
+    if debug_print {
+        println!("{}", map_data);
+    }
+
+    // --ghost <name> and --steps <n> pick the path that --dot overlays and --trace writes.
+    let trace_steps = match get_arg_value(&args, "--steps") {
+        Some(n) => n.parse::<usize>().unwrap(),
+        None => DEFAULTTRACESTEPS,
+    };
+    let trace = match get_arg_value(&args, "--ghost") {
+        Some(name) => {
+            let start = Coord::parse(name.to_string()).unwrap();
+            Some(map_data.get_path_trace(&start, trace_steps).unwrap())
+        }
+        None => None,
+    };
+    if let Some(dot_path) = get_arg_value(&args, "--dot") {
+        let dot = export::to_dot(&map_data, &traversal, trace.as_ref());
+        if let Err(e) = std::fs::write(dot_path, dot) {
+            panic!("Could not write file {}: {}", dot_path, e);
+        }
+    }
+    if let Some(trace_path) = get_arg_value(&args, "--trace") {
+        let trace = match &trace {
+            Some(t) => t,
+            None => panic!("--trace requires a --ghost to follow."),
+        };
+        let mut file = match File::create(trace_path) {
+            Ok(f) => f,
+            Err(e) => panic!("Could not create file {}: {}", trace_path, e),
+        };
+        trace.write_csv(map_data.locations(), &mut file).unwrap();
+    }
+
+    let result = map_data.traverse_map(&traversal, debug_print).unwrap();
+
+    println!("{}", result);
+}
+
+fn get_arg_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    let i = args.iter().position(|a| a == flag)?;
+    return args.get(i + 1).map(|a| a.as_str());
+}
+
+fn open_file<P>(path: P) -> File
+where
+    P: AsRef<Path> + std::fmt::Display,
+{
+    let file = std::fs::File::open(&path);
+    match file {
+        Ok(file) => file,
+        Err(e) => panic!("Could not open file {}: {}", path, e),
+    }
+}
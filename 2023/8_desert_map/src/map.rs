@@ -0,0 +1,253 @@
+use crate::{
+    coord::Coord,
+    export::{PathTrace, TraceStep},
+    ghost_cycle::GhostCycle,
+    location::Locations,
+    matcher::{NodeMatcher, Traversal},
+};
+use aoc_utils::graph;
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+pub struct Map {
+    steps: Vec<bool>,
+    locations: Locations,
+}
+impl Map {
+    pub fn parse(buf: BufReader<File>) -> Result<Self, String> {
+        let mut steps = vec![];
+        let mut locations = Locations::new();
+
+        for (i, l) in buf.lines().enumerate() {
+            let l = match l {
+                Ok(line) => line,
+                Err(e) => return Err(e.to_string()),
+            };
+
+            if l.is_empty() {
+                continue;
+            }
+
+            if i == 0 {
+                Self::parse_steps(l, &mut steps)?;
+                continue;
+            }
+
+            Self::parse_location(l, &mut locations)?;
+        }
+
+        if steps.is_empty() {
+            return Err("No steps to follow.".to_string());
+        }
+        locations.validate()?;
+
+        return Ok(Self { steps, locations });
+    }
+    fn parse_steps(l: String, steps: &mut Vec<bool>) -> Result<(), String> {
+        for c in l.trim().chars() {
+            match c {
+                'L' => steps.push(false),
+                'R' => steps.push(true),
+                _ => return Err(format!("Invalid character in steps line: {}", c)),
+            }
+        }
+        return Ok(());
+    }
+    fn parse_location(l: String, locations: &mut Locations) -> Result<(), String> {
+        let format_err = || format!("Line was not formatted correctly: {}", l);
+
+        let (pos, fork) = l.split_once('=').ok_or_else(format_err)?;
+        let fork = fork
+            .trim()
+            .strip_prefix('(')
+            .and_then(|f| f.strip_suffix(')'))
+            .ok_or_else(format_err)?;
+        let (left, right) = fork.split_once(',').ok_or_else(format_err)?;
+
+        let pos = Coord::parse(pos.to_string())?;
+        let fork = (
+            Coord::parse(left.to_string())?,
+            Coord::parse(right.to_string())?,
+        );
+        locations.insert(pos, fork)?;
+
+        return Ok(());
+    }
+
+    /// Steps until every walker that set off from a `traversal.start` location is on a
+    /// `traversal.end` location at the same time.
+    pub fn traverse_map(&self, traversal: &Traversal, debug_print: bool) -> Result<u64, String> {
+        let ghost_cycles = self.get_ghost_cycles(traversal)?;
+
+        if debug_print {
+            println!("Ghost cycles:");
+            for cycle in ghost_cycles.iter() {
+                println!("\t{}", cycle);
+            }
+        }
+
+        return match GhostCycle::find_first_common_end(&ghost_cycles)? {
+            Some(result) => Ok(result),
+            None => Err("The ghosts never reach end positions at the same time.".to_string()),
+        };
+    }
+
+    pub fn steps(&self) -> &Vec<bool> {
+        return &self.steps;
+    }
+
+    pub fn locations(&self) -> &Locations {
+        return &self.locations;
+    }
+
+    /// The first `steps` steps the ghost starting at `start` takes, including where it
+    /// begins.
+    pub fn get_path_trace(&self, start: &Coord, steps: usize) -> Result<PathTrace, String> {
+        let mut state = match self.locations.get_id(start) {
+            Some(id) => (id, 0),
+            None => return Err(format!("Could not find position {}", start)),
+        };
+
+        let mut result = vec![];
+        for step in 0..=steps {
+            result.push(TraceStep {
+                step,
+                command_index: state.1,
+                node: state.0,
+            });
+            state = self.get_next_state(&state);
+        }
+
+        return Ok(PathTrace::new(result));
+    }
+
+    /// Every location `start` matches, in the order they first appear in the input.
+    pub fn get_starts(&self, start: &NodeMatcher) -> Result<Vec<usize>, String> {
+        let result = (0..self.locations.len())
+            .filter(|&id| start.is_match(self.locations.get_name(id)))
+            .collect::<Vec<usize>>();
+        if result.is_empty() {
+            return Err(format!("No location matches the start pattern {}", start));
+        }
+        return Ok(result);
+    }
+
+    pub fn get_ghost_cycles(&self, traversal: &Traversal) -> Result<Vec<GhostCycle>, String> {
+        let mut result = vec![];
+        for id in self.get_starts(&traversal.start)? {
+            result.push(self.get_ghost_cycle(id, &traversal.end));
+        }
+        return Ok(result);
+    }
+
+    fn get_ghost_cycle(&self, id: usize, end: &NodeMatcher) -> GhostCycle {
+        let next_state = |state: &(usize, usize)| self.get_next_state(state);
+        let cycle = graph::brent((id, 0), next_state);
+
+        let mut tail_ends = vec![];
+        let mut cycle_ends = vec![];
+        let mut state = (id, 0);
+        for step in 0..cycle.tail + cycle.length {
+            if end.is_match(self.locations.get_name(state.0)) {
+                match step < cycle.tail {
+                    true => tail_ends.push(step),
+                    false => cycle_ends.push(step),
+                }
+            }
+            state = next_state(&state);
+        }
+
+        return GhostCycle::new(
+            self.locations.get_name(id).clone(),
+            cycle.tail,
+            cycle.length,
+            tail_ends,
+            cycle_ends,
+        );
+    }
+
+    fn get_next_state(&self, state: &(usize, usize)) -> (usize, usize) {
+        let (curr_pos, command_index) = *state;
+        let next_pos = match self.steps[command_index] {
+            true => self.locations.get_right(curr_pos),
+            false => self.locations.get_left(curr_pos),
+        };
+        return (next_pos, (command_index + 1) % self.steps.len());
+    }
+}
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut result = "Steps:\n\t".to_string();
+        for i in 0..self.steps.len() {
+            let step = if self.steps[i] { 'R' } else { 'L' };
+            result = format!("{}{}", result, step);
+        }
+        result = format!("{}\n{}", result, self.locations);
+        write!(f, "{}", result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::map::Map;
+    use crate::matcher::{NodeMatcher, Traversal};
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::path::Path;
+
+    pub fn open_file<P>(path: P) -> File
+    where
+        P: AsRef<Path> + std::fmt::Display,
+    {
+        let file = std::fs::File::open(&path);
+        match file {
+            Ok(file) => file,
+            Err(e) => panic!("Could not open file {}: {}", path, e),
+        }
+    }
+
+    #[test]
+    fn test_traverse_map_part1() {
+        let buf = BufReader::new(open_file("./test_input_1.txt"));
+        let map = Map::parse(buf).unwrap();
+        assert_eq!(map.traverse_map(&Traversal::part1(), false), Ok(6));
+    }
+
+    #[test]
+    fn test_traverse_map_part2() {
+        let buf = BufReader::new(open_file("./test_input_2.txt"));
+        let map = Map::parse(buf).unwrap();
+        assert_eq!(map.traverse_map(&Traversal::part2(), false), Ok(6));
+        assert!(map.get_starts(&NodeMatcher::parse("*Q").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_parse_no_steps() {
+        let buf = BufReader::new(open_file("./test_input_no_steps.txt"));
+        assert_eq!(
+            Map::parse(buf).err(),
+            Some("No steps to follow.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_traverse_map_offset_cycles() {
+        // 11A first ends at step 3 and every 3 after, but 22A first ends at step 4 and then
+        // every 2, so the answer isn't the lcm of the first arrivals.
+        let buf = BufReader::new(open_file("./test_input_offset.txt"));
+        let map = Map::parse(buf).unwrap();
+        let cycles = map.get_ghost_cycles(&Traversal::part2()).unwrap();
+        assert_eq!(
+            cycles[0].to_string(),
+            "Ghost from 11A: tail 1, cycle length 3, ends in tail: [], ends in cycle: [3]"
+        );
+        assert_eq!(
+            cycles[1].to_string(),
+            "Ghost from 22A: tail 4, cycle length 2, ends in tail: [], ends in cycle: [4]"
+        );
+        assert_eq!(map.traverse_map(&Traversal::part2(), false), Ok(6));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::coord::Coord;
+use std::fmt::Display;
+
+/// Picks out the locations a traversal starts from or ends on.
+pub enum NodeMatcher {
+    Exact(String),
+    Prefix(String),
+    Suffix(String),
+    List(Vec<String>),
+}
+impl NodeMatcher {
+    /// `AAA` matches exactly, `A*` by prefix, `*A` by suffix and `AAA,BBB` any listed name.
+    pub fn parse(pattern: &str) -> Result<Self, String> {
+        let pattern = pattern.trim();
+        if pattern.is_empty() {
+            return Err("Node pattern is empty.".to_string());
+        }
+
+        if pattern.contains(',') {
+            let names = pattern
+                .split(',')
+                .map(|n| Coord::parse(n.to_string()).map(|c| c.val().to_string()))
+                .collect::<Result<Vec<String>, String>>()?;
+            return Ok(NodeMatcher::List(names));
+        }
+        if let Some(suffix) = pattern.strip_prefix('*') {
+            return Ok(NodeMatcher::Suffix(suffix.to_string()));
+        }
+        if let Some(prefix) = pattern.strip_suffix('*') {
+            return Ok(NodeMatcher::Prefix(prefix.to_string()));
+        }
+        return Ok(NodeMatcher::Exact(
+            Coord::parse(pattern.to_string())?.val().to_string(),
+        ));
+    }
+
+    pub fn is_match(&self, name: &Coord) -> bool {
+        use NodeMatcher::*;
+        let name = name.val();
+        return match self {
+            Exact(n) => name == n,
+            Prefix(p) => name.starts_with(p.as_str()),
+            Suffix(s) => name.ends_with(s.as_str()),
+            List(names) => names.iter().any(|n| n == name),
+        };
+    }
+}
+impl Display for NodeMatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use NodeMatcher::*;
+        match self {
+            Exact(n) => write!(f, "{}", n),
+            Prefix(p) => write!(f, "{}*", p),
+            Suffix(s) => write!(f, "*{}", s),
+            List(names) => write!(f, "{}", names.join(",")),
+        }
+    }
+}
+
+/// The set of start and end locations for one way of walking the map.
+pub struct Traversal {
+    pub start: NodeMatcher,
+    pub end: NodeMatcher,
+}
+impl Traversal {
+    pub fn new(start: NodeMatcher, end: NodeMatcher) -> Self {
+        return Self { start, end };
+    }
+
+    /// A single walker from `AAA` to `ZZZ`.
+    pub fn part1() -> Self {
+        return Self::new(
+            NodeMatcher::Exact("AAA".to_string()),
+            NodeMatcher::Exact("ZZZ".to_string()),
+        );
+    }
+
+    /// A ghost from every location ending in `A`, until all of them are on one ending in `Z`.
+    pub fn part2() -> Self {
+        return Self::new(
+            NodeMatcher::Suffix("A".to_string()),
+            NodeMatcher::Suffix("Z".to_string()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::coord::Coord;
+    use crate::matcher::NodeMatcher;
+
+    fn coord(s: &str) -> Coord {
+        return Coord::parse(s.to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_node_matcher_parse() {
+        let exact = NodeMatcher::parse("AAA").unwrap();
+        assert!(exact.is_match(&coord("AAA")));
+        assert!(!exact.is_match(&coord("BAAA")));
+
+        let suffix = NodeMatcher::parse("*Z").unwrap();
+        assert!(suffix.is_match(&coord("11Z")));
+        assert!(!suffix.is_match(&coord("Z11")));
+
+        let prefix = NodeMatcher::parse("11*").unwrap();
+        assert!(prefix.is_match(&coord("11Z")));
+        assert!(!prefix.is_match(&coord("211")));
+
+        let list = NodeMatcher::parse("11A, 22A").unwrap();
+        assert!(list.is_match(&coord("22A")));
+        assert!(!list.is_match(&coord("33A")));
+        assert_eq!(list.to_string(), "11A,22A");
+
+        assert!(NodeMatcher::parse("").is_err());
+        assert!(NodeMatcher::parse("A-A").is_err());
+    }
+}
@@ -1 +0,0 @@
-pub mod sandstorm_data;
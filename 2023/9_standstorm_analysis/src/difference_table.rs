@@ -0,0 +1,354 @@
+use std::{fmt::Display, ops::Range};
+
+use crate::reading::{overflow, Reading};
+
+/// The rows of repeated differences under a history, down to the first row of zeros.
+/// Built once, it can evaluate the history at any index, before or after the readings.
+/// Every step is checked, so a table of `i64` fails where a `BigInt` one stays exact.
+pub struct DifferenceTable<T: Reading = i64> {
+    rows: Vec<Vec<T>>,
+}
+impl<T: Reading> DifferenceTable<T> {
+    pub fn new(readings: &[i64], debug_print: bool) -> Result<Self, String> {
+        if readings.is_empty() {
+            return Err("Can't build a difference table for an empty history.".to_string());
+        }
+
+        let mut rows = vec![readings.iter().map(|&n| T::from_i64(n)).collect::<Vec<T>>()];
+        while rows[rows.len() - 1].iter().any(|n| !n.is_zero()) {
+            let last = &rows[rows.len() - 1];
+            let row = (1..last.len())
+                .map(|i| last[i].checked_sub(&last[i - 1]))
+                .collect::<Option<Vec<T>>>();
+            let row = match row {
+                Some(row) => row,
+                None => return Err(overflow::<T>("taking differences")),
+            };
+            if row.is_empty() {
+                return Err(format!(
+                    "History of {} readings never reaches a row of zero differences.",
+                    readings.len()
+                ));
+            }
+            rows.push(row);
+        }
+
+        let table = Self { rows };
+        if debug_print {
+            println!("{}", table);
+        }
+        return Ok(table);
+    }
+
+    /// How many readings the table was built from.
+    pub fn len(&self) -> usize {
+        return self.rows[0].len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.rows[0].is_empty();
+    }
+
+    pub fn rows(&self) -> &[Vec<T>] {
+        return &self.rows;
+    }
+
+    /// The reading at `index`, where 0 is the first reading. Past the last reading it's
+    /// extrapolated with Newton's backward-difference formula, summing each row's last
+    /// value times a binomial in how far past the end `index` is. Before the first reading
+    /// it's the forward-difference formula on each row's first value, where the binomials
+    /// alternate in sign. The last row is all zeros and adds nothing, so it's skipped
+    /// rather than working out its binomial, which can overflow on its own far from the
+    /// readings. The binomials of the other rows can still overflow before the result
+    /// would, as can the partial sums when the terms cancel out.
+    pub fn get_reading(&self, index: i64) -> Result<T, String> {
+        let len = self.len() as i64;
+        if (0..len).contains(&index) {
+            return Ok(self.rows[0][index as usize].clone());
+        }
+
+        let error = || overflow::<T>(&format!("extrapolating to index {}", index));
+        let (steps, forward) = match index >= len {
+            true => (index - (len - 1), true),
+            false => (index.checked_neg().ok_or_else(error)?, false),
+        };
+
+        let mut result = T::from_i64(0);
+        let mut choose = T::from_i64(1);
+        let last_row = self.rows.len() - 1;
+        for (k, row) in self.rows[0..last_row].iter().enumerate() {
+            if k > 0 {
+                // Each binomial is the one before times (steps + k - 1) / k, exactly.
+                let factor = steps.checked_add(k as i64 - 1).ok_or_else(error)?;
+                let factor = match forward {
+                    true => factor,
+                    false => -factor,
+                };
+                choose = choose
+                    .checked_mul(&T::from_i64(factor))
+                    .and_then(|c| c.checked_div_exact(k as u32))
+                    .ok_or_else(error)?;
+            }
+            let anchor = match forward {
+                true => &row[row.len() - 1],
+                false => &row[0],
+            };
+            result = anchor
+                .checked_mul(&choose)
+                .and_then(|term| result.checked_add(&term))
+                .ok_or_else(error)?;
+        }
+        return Ok(result);
+    }
+
+    /// The reading `steps` past the last one.
+    pub fn get_forward(&self, steps: i64) -> Result<T, String> {
+        let index = (self.len() as i64 - 1).checked_add(steps);
+        return match index {
+            Some(index) => self.get_reading(index),
+            None => Err(format!("Index {} steps forward is out of range.", steps)),
+        };
+    }
+
+    /// The reading `steps` before the first one.
+    pub fn get_backward(&self, steps: i64) -> Result<T, String> {
+        return match steps.checked_neg() {
+            Some(index) => self.get_reading(index),
+            None => Err(format!("Index {} steps back is out of range.", steps)),
+        };
+    }
+
+    pub fn get_range(&self, range: Range<i64>) -> Result<Vec<T>, String> {
+        return range.map(|i| self.get_reading(i)).collect();
+    }
+}
+impl<T: Reading> Display for DifferenceTable<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut result = "Difference table:\n".to_string();
+        for (depth, row) in self.rows.iter().enumerate() {
+            let row = row.iter().map(|n| n.to_string()).collect::<Vec<String>>();
+            result = format!("{}\t{}{}\n", result, "  ".repeat(depth), row.join("  "));
+        }
+        write!(f, "{}", result.trim_end())
+    }
+}
+
+/// The reading right after the last one, or with `backwards` set, right before the
+/// first, without building a table. The differences are taken in place in a single
+/// buffer, one row shorter each time, adding up each row's last value going forwards, or
+/// its first value with alternating signs going backwards, until a row is all zeros.
+pub fn extrapolate<T: Reading>(
+    readings: &[i64],
+    backwards: bool,
+    debug_print: bool,
+) -> Result<T, String> {
+    if readings.is_empty() {
+        return Err("Can't extrapolate an empty history.".to_string());
+    }
+    let what = match backwards {
+        true => "extrapolating the previous reading",
+        false => "extrapolating the next reading",
+    };
+
+    let mut row = readings.iter().map(|&n| T::from_i64(n)).collect::<Vec<T>>();
+    let mut result = T::from_i64(0);
+    if debug_print {
+        println!("Difference table:");
+    }
+    for len in (1..=row.len()).rev() {
+        if debug_print {
+            let values = row[0..len]
+                .iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>();
+            let depth = row.len() - len;
+            println!("\t{}{}", "  ".repeat(depth), values.join("  "));
+        }
+        if row[0..len].iter().all(|n| n.is_zero()) {
+            return Ok(result);
+        }
+        if len == 1 {
+            break;
+        }
+
+        let step = match (backwards, (row.len() - len) % 2 == 1) {
+            (false, _) => result.checked_add(&row[len - 1]),
+            (true, false) => result.checked_add(&row[0]),
+            (true, true) => result.checked_sub(&row[0]),
+        };
+        result = step.ok_or_else(|| overflow::<T>(what))?;
+        for i in 0..len - 1 {
+            row[i] = row[i + 1]
+                .checked_sub(&row[i])
+                .ok_or_else(|| overflow::<T>("taking differences"))?;
+        }
+    }
+
+    return Err(format!(
+        "History of {} readings never reaches a row of zero differences.",
+        readings.len()
+    ));
+}
+
+/// Parses `a..b`, or `a..=b` to include `b`, where either end may be negative.
+pub fn parse_range(s: &str) -> Result<Range<i64>, String> {
+    let (start, end, inclusive) = match s.split_once("..") {
+        Some((start, end)) => match end.strip_prefix('=') {
+            Some(end) => (start, end, true),
+            None => (start, end, false),
+        },
+        None => return Err(format!("Range {} is missing '..'.", s)),
+    };
+
+    let parse = |n: &str| match n.trim().parse::<i64>() {
+        Ok(n) => Ok(n),
+        Err(e) => Err(format!("Invalid range bound {} in {}: {}", n, s, e)),
+    };
+    let (start, mut end) = (parse(start)?, parse(end)?);
+    if inclusive {
+        end = match end.checked_add(1) {
+            Some(n) => n,
+            None => return Err(format!("Range {} ends past the largest index.", s)),
+        };
+    }
+    if end < start {
+        return Err(format!("Range {} ends before it starts.", s));
+    }
+
+    return Ok(start..end);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::difference_table::{extrapolate, parse_range, DifferenceTable};
+    use aoc_utils::math::BigInt;
+
+    #[test]
+    fn test_get_reading() {
+        let table = DifferenceTable::<i64>::new(&[10, 13, 16, 21, 30, 45], false).unwrap();
+        assert_eq!(table.rows().len(), 5);
+        assert_eq!(table.get_reading(0), Ok(10));
+        assert_eq!(table.get_reading(4), Ok(30));
+        assert_eq!(table.get_forward(1), Ok(68));
+        assert_eq!(table.get_backward(1), Ok(5));
+        assert_eq!(
+            table.get_range(-3..9),
+            Ok(vec![-19, -4, 5, 10, 13, 16, 21, 30, 45, 68, 101, 146])
+        );
+        assert!(DifferenceTable::<i64>::new(&[1, 2, 4, 8], false).is_err());
+        assert!(DifferenceTable::<i64>::new(&[], false).is_err());
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let readings = [10, 13, 16, 21, 30, 45];
+        assert_eq!(extrapolate::<i64>(&readings, false, false), Ok(68));
+        assert_eq!(extrapolate::<i64>(&readings, true, false), Ok(5));
+        assert_eq!(extrapolate::<i64>(&[0, 0], false, false), Ok(0));
+        assert!(extrapolate::<i64>(&[7], true, false).is_err());
+
+        // Every history agrees with its difference table.
+        for readings in [
+            vec![0, 3, 6, 9, 12, 15],
+            vec![1, 3, 6, 10, 15, 21],
+            vec![-4, -4],
+        ] {
+            let table = DifferenceTable::<i64>::new(&readings, false).unwrap();
+            assert_eq!(extrapolate(&readings, false, false), table.get_forward(1));
+            assert_eq!(extrapolate(&readings, true, false), table.get_backward(1));
+        }
+
+        assert!(extrapolate::<i64>(&[], false, false).is_err());
+        let error = extrapolate::<i64>(&[1, 2, 4, 8], false, false).unwrap_err();
+        assert_eq!(
+            error,
+            "History of 4 readings never reaches a row of zero differences."
+        );
+        let edge = [-i64::MAX, 0, i64::MAX];
+        let error = extrapolate::<i64>(&edge, false, false).unwrap_err();
+        assert!(error.starts_with("Overflowed i64 arithmetic"), "{}", error);
+        assert_eq!(
+            extrapolate::<BigInt>(&edge, true, false)
+                .unwrap()
+                .to_string(),
+            "-18446744073709551614"
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        // A step of 2^63 - 1 overflows on the next reading in either direction.
+        let edge = [-i64::MAX, 0, i64::MAX];
+        let table = DifferenceTable::<i64>::new(&edge, false).unwrap();
+        assert_eq!(table.get_forward(0), Ok(i64::MAX));
+        let error = table.get_forward(1).unwrap_err();
+        assert!(error.starts_with("Overflowed i64 arithmetic"), "{}", error);
+        assert!(table.get_backward(1).is_err());
+
+        let table = DifferenceTable::<BigInt>::new(&edge, false).unwrap();
+        assert_eq!(
+            table.get_forward(1).unwrap().to_string(),
+            "18446744073709551614"
+        );
+        assert_eq!(
+            table.get_backward(1).unwrap().to_string(),
+            "-18446744073709551614"
+        );
+
+        // Differences between the extremes don't fit in an i64, but they do in a BigInt, so
+        // this parabola only fails as an i64 table.
+        let parabola = [i64::MIN, i64::MAX, i64::MAX, i64::MIN];
+        let table = DifferenceTable::<i64>::new(&parabola, false);
+        assert!(matches!(
+            table,
+            Err(e) if e == "Overflowed i64 arithmetic while taking differences."
+        ));
+        let table = DifferenceTable::<BigInt>::new(&parabola, false).unwrap();
+        assert_eq!(table.rows().len(), 4);
+        assert_eq!(
+            table.get_forward(1).unwrap().to_string(),
+            "-46116860184273879038"
+        );
+        assert_eq!(
+            table.get_backward(1).unwrap(),
+            table.get_forward(1).unwrap()
+        );
+
+        // A BigInt table never overflows, but three points that aren't on a line still
+        // run out of readings before reaching a row of zeros.
+        let table = DifferenceTable::<BigInt>::new(&[i64::MIN, i64::MAX, i64::MIN], false);
+        assert!(matches!(
+            table,
+            Err(e) if e == "History of 3 readings never reaches a row of zero differences."
+        ));
+
+        // The zero row's binomial, 5e9 choose 2, overflows, but the line's next reading
+        // doesn't need it.
+        let table = DifferenceTable::<i64>::new(&[0, 1, 2], false).unwrap();
+        assert_eq!(table.get_reading(5_000_000_000), Ok(5_000_000_000));
+        assert_eq!(table.get_reading(-5_000_000_000), Ok(-5_000_000_000));
+
+        // Cubes scaled by 2^56 fit up to 5^3 but not 6^3.
+        let cubes = (0..5).map(|n| (n * n * n) << 56).collect::<Vec<i64>>();
+        let table = DifferenceTable::<i64>::new(&cubes, false).unwrap();
+        assert_eq!(table.get_forward(1), Ok(125 << 56));
+        assert!(table.get_forward(2).is_err());
+        let table = DifferenceTable::<BigInt>::new(&cubes, false).unwrap();
+        assert_eq!(table.get_forward(2).unwrap(), BigInt::from(216i128 << 56));
+        assert_eq!(
+            table.get_reading(-1000).unwrap(),
+            BigInt::from(-(10i128.pow(9)) << 56)
+        );
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("-2..3").unwrap(), -2..3);
+        assert_eq!(parse_range("0..=5").unwrap(), 0..6);
+        assert!(parse_range("5").is_err());
+        assert!(parse_range("3..1").is_err());
+        assert!(parse_range("a..b").is_err());
+        assert_eq!(parse_range("0..=9223372036854775806").unwrap(), 0..i64::MAX);
+        assert!(parse_range("0..=9223372036854775807").is_err());
+    }
+}
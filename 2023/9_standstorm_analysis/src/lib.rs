@@ -0,0 +1,5 @@
+pub mod difference_table;
+pub mod polynomial;
+pub mod reading;
+pub mod sandstorm_data;
+pub mod stream;
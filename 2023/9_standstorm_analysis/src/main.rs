@@ -0,0 +1,135 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter};
+use std::path::Path;
+
+use aoc_utils::math::BigInt;
+use sandstorm_analysis::{
+    difference_table::parse_range,
+    reading::{self, Reading},
+    sandstorm_data::Sandstorms,
+    stream::stream,
+};
+
+const TESTFILEPATH: &str = "./test_input.txt";
+const FILEPATH: &str = "./input.txt";
+
+fn main() {
+    let args = env::args().collect::<Vec<String>>();
+    let part = match get_arg_value(&args, "--part") {
+        Some(p) => p.parse::<u8>().unwrap(),
+        None => 1,
+    };
+    let backwards = match part {
+        1 => false,
+        2 => true,
+        _ => panic!("Unknown part {}, expected 1 or 2.", part),
+    };
+    // --input reads histories from another file, or from stdin given -.
+    let is_test = args.contains(&"-t".to_string());
+    let file_path = match (get_arg_value(&args, "--input"), is_test) {
+        (Some(path), _) => path,
+        (None, true) => TESTFILEPATH,
+        (None, false) => FILEPATH,
+    };
+    let debug_print = args.contains(&"-d".to_string());
+    let big = args.contains(&"--big".to_string());
+
+    // --stream writes each history's next and previous readings as it's read, along with
+    // running totals, rather than loading every history first.
+    if args.contains(&"--stream".to_string()) {
+        let reader: Box<dyn BufRead> = match file_path {
+            "-" => Box::new(io::stdin().lock()),
+            path => Box::new(BufReader::new(open_file(path))),
+        };
+        let mut writer = BufWriter::new(io::stdout().lock());
+        match big {
+            true => stream::<BigInt, _, _>(reader, &mut writer, debug_print).map(|_| ()),
+            false => stream::<i64, _, _>(reader, &mut writer, debug_print).map(|_| ()),
+        }
+        .unwrap();
+        return;
+    }
+
+    let buf = BufReader::new(open_file(file_path));
+    let sandstorm_data = Sandstorms::parse(buf).unwrap();
+
+    if debug_print {
+        println!("{}", sandstorm_data);
+    }
+
+    // --fit prints the polynomial behind every history.
+    if args.contains(&"--fit".to_string()) {
+        let polynomials = sandstorm_data.get_polynomials().unwrap();
+        for (i, polynomial) in polynomials.iter().enumerate() {
+            println!(
+                "{}: degree {}: {}",
+                sandstorm_data.get_value(i),
+                polynomial.degree(),
+                polynomial
+            );
+        }
+        return;
+    }
+
+    // --big extrapolates with big integers, which never overflow, instead of i64.
+    match big {
+        true => extrapolate::<BigInt>(&args, &sandstorm_data, backwards, debug_print),
+        false => extrapolate::<i64>(&args, &sandstorm_data, backwards, debug_print),
+    }
+}
+
+fn extrapolate<T: Reading>(
+    args: &[String],
+    sandstorm_data: &Sandstorms,
+    backwards: bool,
+    debug_print: bool,
+) {
+    // --at <index> evaluates every history at that index and --range a..b across a range
+    // of them, where 0 is the first reading and negative indices come before it.
+    if let Some(index) = get_arg_value(args, "--at") {
+        let index = index.parse::<i64>().unwrap();
+        let readings = sandstorm_data
+            .get_readings_at::<T>(index, debug_print)
+            .unwrap();
+        println!("{}", reading::sum(&readings).unwrap());
+        return;
+    }
+    if let Some(range) = get_arg_value(args, "--range") {
+        let range = parse_range(range).unwrap();
+        let readings = sandstorm_data
+            .get_readings_in::<T>(range.clone(), debug_print)
+            .unwrap();
+        for (offset, index) in range.enumerate() {
+            let column = readings
+                .iter()
+                .map(|r| r[offset].clone())
+                .collect::<Vec<T>>();
+            println!("{}: {}", index, reading::sum(&column).unwrap());
+        }
+        return;
+    }
+
+    let sandstorm_data_next_readings =
+        sandstorm_data.get_next_readings::<T>(backwards, debug_print);
+
+    let result = reading::sum(&sandstorm_data_next_readings.unwrap()).unwrap();
+
+    println!("{}", result);
+}
+
+fn get_arg_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    let i = args.iter().position(|a| a == flag)?;
+    return args.get(i + 1).map(|a| a.as_str());
+}
+
+fn open_file<P>(path: P) -> File
+where
+    P: AsRef<Path> + std::fmt::Display,
+{
+    let file = std::fs::File::open(&path);
+    match file {
+        Ok(file) => file,
+        Err(e) => panic!("Could not open file {}: {}", path, e),
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt::Display;
+
+use aoc_utils::math::Rational;
+
+/// A polynomial in the reading index with exact rational coefficients, where index 0 is
+/// a history's first reading.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Polynomial {
+    coefficients: Vec<Rational>,
+}
+impl Polynomial {
+    /// Fits the polynomial of lowest degree through `readings`. The differences are taken
+    /// in place until a row is all zeros, keeping the first value of each row as the
+    /// Newton forward-difference coefficients, which are then expanded into powers of the
+    /// index. Histories that run out of readings before reaching a row of zeros aren't
+    /// known to be polynomial, so they're rejected.
+    pub fn fit(readings: &[i64]) -> Result<Self, String> {
+        if readings.is_empty() {
+            return Err("Can't fit a polynomial to an empty history.".to_string());
+        }
+
+        let mut row = readings.iter().map(|&n| n as i128).collect::<Vec<i128>>();
+        let mut newton = vec![];
+        for len in (1..=row.len()).rev() {
+            if row[0..len].iter().all(|&n| n == 0) {
+                break;
+            }
+            if len == 1 {
+                return Err(format!(
+                    "History of {} readings never reaches a row of zero differences.",
+                    readings.len()
+                ));
+            }
+
+            newton.push(row[0]);
+            for i in 0..len - 1 {
+                row[i] = match row[i + 1].checked_sub(row[i]) {
+                    Some(n) => n,
+                    None => return Err("Difference overflowed while fitting.".to_string()),
+                };
+            }
+        }
+
+        // The k-th Newton term is its coefficient times index choose k, which is the
+        // falling factorial index * (index - 1) * ... * (index - k + 1) over k!.
+        let overflow = || "Coefficient overflowed while fitting.".to_string();
+        let mut coefficients = vec![Rational::zero(); newton.len().max(1)];
+        let mut choose = vec![Rational::one()];
+        for (k, &difference) in newton.iter().enumerate() {
+            if k > 0 {
+                let shift = Rational::from_int(k as i128 - 1);
+                let divisor = Rational::from_int(k as i128);
+                let mut next = vec![Rational::zero(); choose.len() + 1];
+                for (power, c) in choose.iter().enumerate() {
+                    let c = c.checked_div(&divisor).ok_or_else(overflow)?;
+                    next[power + 1] = next[power + 1].checked_add(&c).ok_or_else(overflow)?;
+                    let shifted = c.checked_mul(&shift).ok_or_else(overflow)?;
+                    next[power] = next[power].checked_sub(&shifted).ok_or_else(overflow)?;
+                }
+                choose = next;
+            }
+
+            let difference = Rational::from_int(difference);
+            for (power, c) in choose.iter().enumerate() {
+                let term = c.checked_mul(&difference).ok_or_else(overflow)?;
+                coefficients[power] = coefficients[power]
+                    .checked_add(&term)
+                    .ok_or_else(overflow)?;
+            }
+        }
+
+        return Ok(Self { coefficients });
+    }
+
+    /// The highest power with a non-zero coefficient, taking the zero polynomial as
+    /// degree 0.
+    pub fn degree(&self) -> usize {
+        return self
+            .coefficients
+            .iter()
+            .rposition(|c| !c.is_zero())
+            .unwrap_or_default();
+    }
+
+    /// Coefficients of each power of the index, starting from the constant term.
+    pub fn coefficients(&self) -> &[Rational] {
+        return &self.coefficients[0..=self.degree()];
+    }
+
+    /// The polynomial's value at `index`, which is always a whole number at whole indices
+    /// since the polynomial came from whole readings.
+    pub fn evaluate(&self, index: i64) -> Result<i128, String> {
+        let overflow = || format!("Evaluating at index {} overflowed.", index);
+        let index = Rational::from(index);
+        let mut result = Rational::zero();
+        for c in self.coefficients().iter().rev() {
+            result = result
+                .checked_mul(&index)
+                .and_then(|r| r.checked_add(c))
+                .ok_or_else(overflow)?;
+        }
+
+        return match result.to_integer() {
+            Some(n) => Ok(n),
+            None => Err(format!(
+                "Polynomial isn't whole at index {}: {}",
+                index, result
+            )),
+        };
+    }
+}
+impl Display for Polynomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut result = "".to_string();
+        for (power, c) in self.coefficients().iter().enumerate().rev() {
+            if c.is_zero() && !(power == 0 && result.is_empty()) {
+                continue;
+            }
+
+            let (sign, c) = match (result.is_empty(), c.num() < 0) {
+                (true, true) => ("-", -*c),
+                (true, false) => ("", *c),
+                (false, true) => (" - ", -*c),
+                (false, false) => (" + ", *c),
+            };
+            let coefficient = match (c == Rational::one(), power) {
+                (true, 0) | (false, _) => c.to_string(),
+                (true, _) => "".to_string(),
+            };
+            let variable = match power {
+                0 => "".to_string(),
+                1 => "x".to_string(),
+                _ => format!("x^{}", power),
+            };
+            let gap = if coefficient.is_empty() || variable.is_empty() {
+                ""
+            } else {
+                " "
+            };
+            result = format!("{}{}{}{}{}", result, sign, coefficient, gap, variable);
+        }
+        write!(f, "{}", result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::polynomial::Polynomial;
+    use aoc_utils::math::Rational;
+
+    fn rational(num: i128, den: i128) -> Rational {
+        return Rational::new(num, den).unwrap();
+    }
+
+    #[test]
+    fn test_fit() {
+        let linear = Polynomial::fit(&[0, 3, 6, 9, 12, 15]).unwrap();
+        assert_eq!(linear.degree(), 1);
+        assert_eq!(linear.coefficients(), &[rational(0, 1), rational(3, 1)]);
+        assert_eq!(linear.to_string(), "3 x");
+
+        // The triangular numbers plus one: (x^2 + 3x + 2) / 2.
+        let triangular = Polynomial::fit(&[1, 3, 6, 10, 15, 21]).unwrap();
+        assert_eq!(triangular.degree(), 2);
+        assert_eq!(
+            triangular.coefficients(),
+            &[rational(1, 1), rational(3, 2), rational(1, 2)]
+        );
+        assert_eq!(triangular.to_string(), "1/2 x^2 + 3/2 x + 1");
+        assert_eq!(triangular.evaluate(6), Ok(28));
+        assert_eq!(triangular.evaluate(-1), Ok(0));
+
+        let cubic = Polynomial::fit(&[10, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(cubic.degree(), 3);
+        assert_eq!(cubic.evaluate(6), Ok(68));
+        assert_eq!(cubic.evaluate(-1), Ok(5));
+        assert_eq!(cubic.evaluate(-3), Ok(-19));
+
+        let constant = Polynomial::fit(&[-4, -4]).unwrap();
+        assert_eq!(
+            (constant.degree(), constant.to_string()),
+            (0, "-4".to_string())
+        );
+        let zero = Polynomial::fit(&[0, 0, 0]).unwrap();
+        assert_eq!((zero.degree(), zero.to_string()), (0, "0".to_string()));
+        assert_eq!(
+            Polynomial::fit(&[2, -1, -4]).unwrap().to_string(),
+            "-3 x + 2"
+        );
+    }
+
+    #[test]
+    fn test_fit_errors() {
+        assert!(Polynomial::fit(&[]).is_err());
+        assert!(Polynomial::fit(&[5]).is_err());
+        // Powers of two never settle into a row of zeros.
+        assert!(Polynomial::fit(&[1, 2, 4, 8, 16, 32]).is_err());
+        assert!(Polynomial::fit(&[1, 2, 4, 8, 16, 31]).is_ok());
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::Display;
+
+use aoc_utils::math::BigInt;
+
+/// A number type readings can be extrapolated in. `i64` is fast but fails on overflow,
+/// while `BigInt` is slower and always exact.
+pub trait Reading: Clone + Display + Sized {
+    /// Names the type in overflow errors.
+    const NAME: &'static str;
+
+    fn from_i64(n: i64) -> Self;
+    fn is_zero(&self) -> bool;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+    /// Divides by a small positive number that's known to divide exactly.
+    fn checked_div_exact(&self, divisor: u32) -> Option<Self>;
+}
+impl Reading for i64 {
+    const NAME: &'static str = "i64";
+
+    fn from_i64(n: i64) -> Self {
+        return n;
+    }
+
+    fn is_zero(&self) -> bool {
+        return *self == 0;
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        return i64::checked_add(*self, *other);
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        return i64::checked_sub(*self, *other);
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        return i64::checked_mul(*self, *other);
+    }
+
+    fn checked_div_exact(&self, divisor: u32) -> Option<Self> {
+        return i64::checked_div(*self, divisor as i64);
+    }
+}
+impl Reading for BigInt {
+    const NAME: &'static str = "big integer";
+
+    fn from_i64(n: i64) -> Self {
+        return BigInt::from(n);
+    }
+
+    fn is_zero(&self) -> bool {
+        return BigInt::is_zero(self);
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        return Some(self + other);
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        return Some(self - other);
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        return Some(self * other);
+    }
+
+    fn checked_div_exact(&self, divisor: u32) -> Option<Self> {
+        return self.div_rem(divisor).map(|(quotient, _)| quotient);
+    }
+}
+
+/// The error for a step of `what` that doesn't fit in `T`.
+pub fn overflow<T: Reading>(what: &str) -> String {
+    return format!("Overflowed {} arithmetic while {}.", T::NAME, what);
+}
+
+/// Adds up `readings`, failing if the total doesn't fit.
+pub fn sum<T: Reading>(readings: &[T]) -> Result<T, String> {
+    let mut result = T::from_i64(0);
+    for reading in readings.iter() {
+        result = match result.checked_add(reading) {
+            Some(n) => n,
+            None => return Err(overflow::<T>("summing readings")),
+        };
+    }
+
+    return Ok(result);
+}
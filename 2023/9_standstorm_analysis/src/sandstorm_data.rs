@@ -0,0 +1,237 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{BufRead, BufReader},
+    ops::Range,
+};
+
+use crate::{
+    difference_table::{self, DifferenceTable},
+    polynomial::Polynomial,
+    reading::Reading,
+};
+
+pub struct Sandstorm(Vec<i64>);
+impl Sandstorm {
+    pub fn parse(l: String) -> Result<Self, String> {
+        let mut result = vec![];
+
+        let parse_num =
+            |numeric_string: &mut String, result: &mut Vec<i64>| -> Result<(), String> {
+                match numeric_string.parse() {
+                    Ok(n) => result.push(n),
+                    Err(e) => return Err(e.to_string()),
+                };
+                numeric_string.clear();
+                return Ok(());
+            };
+
+        let mut numeric_string = "".to_string();
+        for c in l.chars() {
+            match c {
+                '-' | '0'..='9' => {
+                    numeric_string.push(c);
+                }
+                _ => parse_num(&mut numeric_string, &mut result)?,
+            }
+        }
+        if !numeric_string.is_empty() {
+            parse_num(&mut numeric_string, &mut result)?;
+        }
+
+        return Ok(Sandstorm(result));
+    }
+
+    pub fn get_value(&self, i: usize) -> i64 {
+        return self.0[i];
+    }
+
+    pub fn len(&self) -> usize {
+        return self.0.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.0.is_empty();
+    }
+
+    pub fn get_difference_table<T: Reading>(
+        &self,
+        debug_print: bool,
+    ) -> Result<DifferenceTable<T>, String> {
+        if debug_print {
+            println!("Starting reading for Sandstorm: {}", self);
+        }
+        return DifferenceTable::new(&self.0, debug_print);
+    }
+
+    /// The lowest-degree polynomial through every reading, in terms of the reading index.
+    pub fn get_polynomial(&self) -> Result<Polynomial, String> {
+        return Polynomial::fit(&self.0);
+    }
+
+    pub fn get_next_reading<T: Reading>(&self, debug_print: bool) -> Result<T, String> {
+        if debug_print {
+            println!("Starting reading for Sandstorm: {}", self);
+        }
+        return difference_table::extrapolate(&self.0, false, debug_print);
+    }
+
+    /// Extrapolates the reading that came before the first one.
+    pub fn get_previous_reading<T: Reading>(&self, debug_print: bool) -> Result<T, String> {
+        if debug_print {
+            println!("Starting reading for Sandstorm: {}", self);
+        }
+        return difference_table::extrapolate(&self.0, true, debug_print);
+    }
+
+    /// The reading at `index`, where 0 is the first reading and indices past either end
+    /// are extrapolated.
+    pub fn get_reading_at<T: Reading>(&self, index: i64, debug_print: bool) -> Result<T, String> {
+        return self.get_difference_table(debug_print)?.get_reading(index);
+    }
+}
+impl Display for Sandstorm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut result = "[".to_string();
+        for i in 0..self.0.len() {
+            let sep = if result.len() != 1 { ", " } else { "" };
+            result = format!("{}{}{}", result, sep, self.0[i]);
+        }
+        result = format!("{}]", result);
+        write!(f, "{}", result)
+    }
+}
+
+pub struct Sandstorms(Vec<Sandstorm>);
+impl Sandstorms {
+    pub fn parse(buf: BufReader<File>) -> Result<Self, String> {
+        let mut result = vec![];
+
+        for l in buf.lines() {
+            let l = match l {
+                Ok(line) => line,
+                Err(e) => return Err(e.to_string()),
+            };
+            result.push(Sandstorm::parse(l)?);
+        }
+
+        return Ok(Self(result));
+    }
+
+    pub fn get_value(&self, i: usize) -> &Sandstorm {
+        return &self.0[i];
+    }
+
+    /// Extrapolates every history forwards, or with `backwards` set, the reading before
+    /// each one starts.
+    pub fn get_next_readings<T: Reading>(
+        &self,
+        backwards: bool,
+        debug_print: bool,
+    ) -> Result<Vec<T>, String> {
+        let mut result = vec![];
+        for i in 0..self.0.len() {
+            result.push(match backwards {
+                true => self.0[i].get_previous_reading(debug_print)?,
+                false => self.0[i].get_next_reading(debug_print)?,
+            });
+        }
+
+        return Ok(result);
+    }
+
+    /// Every history's reading at `index`, extrapolating where it's out of range.
+    pub fn get_readings_at<T: Reading>(
+        &self,
+        index: i64,
+        debug_print: bool,
+    ) -> Result<Vec<T>, String> {
+        return self
+            .0
+            .iter()
+            .map(|s| s.get_reading_at(index, debug_print))
+            .collect();
+    }
+
+    pub fn get_polynomials(&self) -> Result<Vec<Polynomial>, String> {
+        return self.0.iter().map(|s| s.get_polynomial()).collect();
+    }
+
+    /// Every history's readings across `range`, building each difference table once.
+    pub fn get_readings_in<T: Reading>(
+        &self,
+        range: Range<i64>,
+        debug_print: bool,
+    ) -> Result<Vec<Vec<T>>, String> {
+        let mut result = vec![];
+        for sandstorm in self.0.iter() {
+            let table = sandstorm.get_difference_table(debug_print)?;
+            result.push(table.get_range(range.clone())?);
+        }
+
+        return Ok(result);
+    }
+}
+impl Display for Sandstorms {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut result = "Sandstorm data:\n".to_string();
+        for i in 0..self.0.len() {
+            result = format!("{}\t{}\n", result, self.0[i]);
+        }
+        write!(f, "{}", result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sandstorm_data::Sandstorms;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::path::Path;
+
+    pub fn open_file<P>(path: P) -> File
+    where
+        P: AsRef<Path> + std::fmt::Display,
+    {
+        let file = std::fs::File::open(&path);
+        match file {
+            Ok(file) => file,
+            Err(e) => panic!("Could not open file {}: {}", path, e),
+        }
+    }
+
+    #[test]
+    fn test_next_readings() {
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        let sandstorms = Sandstorms::parse(buf).unwrap();
+        let readings = sandstorms.get_next_readings::<i64>(false, false).unwrap();
+        assert_eq!(readings, vec![18, 28, 68]);
+        assert_eq!(readings.iter().sum::<i64>(), 114);
+    }
+
+    #[test]
+    fn test_previous_readings() {
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        let sandstorms = Sandstorms::parse(buf).unwrap();
+        let readings = sandstorms.get_next_readings::<i64>(true, false).unwrap();
+        assert_eq!(readings, vec![-3, 0, 5]);
+        assert_eq!(readings.iter().sum::<i64>(), 2);
+    }
+
+    #[test]
+    fn test_readings_at() {
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        let sandstorms = Sandstorms::parse(buf).unwrap();
+        assert_eq!(
+            sandstorms.get_readings_at::<i64>(6, false).unwrap(),
+            vec![18, 28, 68]
+        );
+        assert_eq!(
+            sandstorms.get_readings_at::<i64>(-1, false).unwrap(),
+            vec![-3, 0, 5]
+        );
+
+        let readings = sandstorms.get_readings_in::<i64>(6..8, false).unwrap();
+        assert_eq!(readings, vec![vec![18, 21], vec![28, 36], vec![68, 101]]);
+    }
+}
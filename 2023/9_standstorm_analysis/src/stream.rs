@@ -0,0 +1,140 @@
+use std::io::{BufRead, Write};
+
+use crate::{
+    reading::{overflow, Reading},
+    sandstorm_data::Sandstorm,
+};
+
+/// Running totals of the readings predicted so far.
+#[derive(Debug, PartialEq, Eq)]
+pub struct StreamTotals<T: Reading> {
+    pub histories: usize,
+    pub next: T,
+    pub previous: T,
+}
+
+/// Reads one history per line from `reader` and writes each line's next and previous
+/// readings to `writer` as soon as they're known, along with the totals so far, so only
+/// one history is held in memory at a time. Blank lines are skipped. Output is
+/// tab-separated, with a header and then one row per history of its line number, next
+/// and previous readings, and the totals of each up to that line.
+pub fn stream<T, R, W>(
+    mut reader: R,
+    writer: &mut W,
+    debug_print: bool,
+) -> Result<StreamTotals<T>, String>
+where
+    T: Reading,
+    R: BufRead,
+    W: Write,
+{
+    let write_error = |e: std::io::Error| format!("Couldn't write prediction: {}", e);
+    writeln!(writer, "line\tnext\tprevious\tnext_total\tprevious_total").map_err(write_error)?;
+
+    let mut totals = StreamTotals {
+        histories: 0,
+        next: T::from_i64(0),
+        previous: T::from_i64(0),
+    };
+    let mut line = String::new();
+    let mut line_number = 0;
+    loop {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => line_number += 1,
+            Err(e) => return Err(format!("Couldn't read line {}: {}", line_number + 1, e)),
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let in_line = |e: String| format!("Line {}: {}", line_number, e);
+        let sandstorm = Sandstorm::parse(line.trim_end().to_string()).map_err(in_line)?;
+        let table = sandstorm
+            .get_difference_table::<T>(debug_print)
+            .map_err(in_line)?;
+        let next = table.get_forward(1).map_err(in_line)?;
+        let previous = table.get_backward(1).map_err(in_line)?;
+
+        totals.histories += 1;
+        totals.next = match totals.next.checked_add(&next) {
+            Some(n) => n,
+            None => return Err(in_line(overflow::<T>("totalling next readings"))),
+        };
+        totals.previous = match totals.previous.checked_add(&previous) {
+            Some(n) => n,
+            None => return Err(in_line(overflow::<T>("totalling previous readings"))),
+        };
+
+        writeln!(
+            writer,
+            "{}\t{}\t{}\t{}\t{}",
+            line_number, next, previous, totals.next, totals.previous
+        )
+        .map_err(write_error)?;
+    }
+
+    writer.flush().map_err(write_error)?;
+    return Ok(totals);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream::{stream, StreamTotals};
+    use aoc_utils::math::BigInt;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::path::Path;
+
+    pub fn open_file<P>(path: P) -> File
+    where
+        P: AsRef<Path> + std::fmt::Display,
+    {
+        let file = std::fs::File::open(&path);
+        match file {
+            Ok(file) => file,
+            Err(e) => panic!("Could not open file {}: {}", path, e),
+        }
+    }
+
+    #[test]
+    fn test_stream() {
+        let buf = BufReader::new(open_file("./test_input.txt"));
+        let mut output = vec![];
+        let totals = stream::<i64, _, _>(buf, &mut output, false).unwrap();
+        assert_eq!(
+            totals,
+            StreamTotals {
+                histories: 3,
+                next: 114,
+                previous: 2
+            }
+        );
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "line\tnext\tprevious\tnext_total\tprevious_total\n\
+             1\t18\t-3\t18\t-3\n\
+             2\t28\t0\t46\t-3\n\
+             3\t68\t5\t114\t2\n"
+        );
+    }
+
+    #[test]
+    fn test_stream_errors() {
+        let input = format!("1 2 3\n\n{} {} {}\n", i64::MAX - 2, i64::MAX - 1, i64::MAX);
+        let mut output = vec![];
+        let error = stream::<i64, _, _>(input.as_bytes(), &mut output, false).unwrap_err();
+        assert!(error.starts_with("Line 3: Overflowed i64"), "{}", error);
+        // Everything before the bad line was still written.
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .ends_with("1\t4\t0\t4\t0\n"));
+
+        let totals = stream::<BigInt, _, _>(input.as_bytes(), &mut vec![], false).unwrap();
+        assert_eq!(totals.next.to_string(), "9223372036854775812");
+
+        let error = stream::<i64, _, _>("1 2 x\n".as_bytes(), &mut vec![], false);
+        assert!(error.unwrap_err().starts_with("Line 1:"));
+    }
+}
@@ -0,0 +1,501 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// Step counts from `start` to every node reachable through `neighbours`.
+pub fn bfs<N, F, I>(start: N, mut neighbours: F) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = HashMap::new();
+    let mut queue = VecDeque::new();
+    result.insert(start.clone(), 0);
+    queue.push_back(start);
+
+    while let Some(curr) = queue.pop_front() {
+        let curr_distance = result[&curr];
+        for next in neighbours(&curr) {
+            if result.contains_key(&next) {
+                continue;
+            }
+            result.insert(next.clone(), curr_distance + 1);
+            queue.push_back(next);
+        }
+    }
+
+    return result;
+}
+
+/// Shortest path by step count from `start` to the first node that satisfies `is_goal`,
+/// including both ends. Returns `None` if no reachable node is a goal.
+pub fn bfs_path<N, F, I, G>(start: N, mut neighbours: F, mut is_goal: G) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    parents.insert(start.clone(), None);
+    queue.push_back(start);
+
+    while let Some(curr) = queue.pop_front() {
+        if is_goal(&curr) {
+            return Some(unwind_path(&parents, curr));
+        }
+        for next in neighbours(&curr) {
+            if parents.contains_key(&next) {
+                continue;
+            }
+            parents.insert(next.clone(), Some(curr.clone()));
+            queue.push_back(next);
+        }
+    }
+
+    return None;
+}
+
+/// Depth-first pre-order of every node reachable from `start`. Neighbours are visited in
+/// the order `neighbours` yields them.
+pub fn dfs<N, F, I>(start: N, mut neighbours: F) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = vec![];
+    let mut visited = HashSet::new();
+    let mut stack = vec![start];
+
+    while let Some(curr) = stack.pop() {
+        if !visited.insert(curr.clone()) {
+            continue;
+        }
+        let mut next_nodes = neighbours(&curr)
+            .into_iter()
+            .filter(|n| !visited.contains(n))
+            .collect::<Vec<N>>();
+        next_nodes.reverse();
+        stack.append(&mut next_nodes);
+        result.push(curr);
+    }
+
+    return result;
+}
+
+/// Lowest total cost from `start` to every reachable node, where `neighbours` yields
+/// `(node, edge cost)` pairs.
+pub fn dijkstra<N, F, I>(start: N, mut neighbours: F) -> HashMap<N, u64>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut result = HashMap::new();
+    let mut nodes = vec![start.clone()];
+    let mut heap = BinaryHeap::new();
+    let mut best = HashMap::new();
+    best.insert(start, 0);
+    heap.push(Reverse((0, 0)));
+
+    while let Some(Reverse((cost, i))) = heap.pop() {
+        let curr = nodes[i].clone();
+        if result.contains_key(&curr) {
+            continue;
+        }
+        result.insert(curr.clone(), cost);
+        for (next, edge_cost) in neighbours(&curr) {
+            let next_cost = cost + edge_cost;
+            if result.contains_key(&next) || best.get(&next).is_some_and(|&c| c <= next_cost) {
+                continue;
+            }
+            best.insert(next.clone(), next_cost);
+            nodes.push(next);
+            heap.push(Reverse((next_cost, nodes.len() - 1)));
+        }
+    }
+
+    return result;
+}
+
+/// Lowest cost path from `start` to the first node that satisfies `is_goal`, along with its
+/// total cost.
+pub fn dijkstra_path<N, F, I, G>(start: N, neighbours: F, is_goal: G) -> Option<(Vec<N>, u64)>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+    G: FnMut(&N) -> bool,
+{
+    return astar(start, neighbours, |_| 0, is_goal);
+}
+
+/// A* search. `heuristic` must be consistent: it never overestimates the remaining cost
+/// to a goal, and never drops by more than an edge's cost along that edge. Nodes aren't
+/// revisited once expanded, so with a heuristic that's only admissible the returned path
+/// may not be the cheapest.
+pub fn astar<N, F, I, H, G>(
+    start: N,
+    mut neighbours: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<N>, u64)>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+    H: FnMut(&N) -> u64,
+    G: FnMut(&N) -> bool,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut best: HashMap<N, u64> = HashMap::new();
+    let mut closed = HashSet::new();
+    let mut nodes = vec![(start.clone(), None)];
+    let mut heap = BinaryHeap::new();
+    best.insert(start.clone(), 0);
+    heap.push(Reverse((heuristic(&start), 0, 0)));
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        let (curr, parent) = nodes[i].clone();
+        if !closed.insert(curr.clone()) {
+            continue;
+        }
+        parents.insert(curr.clone(), parent);
+        if is_goal(&curr) {
+            return Some((unwind_path(&parents, curr), cost));
+        }
+        for (next, edge_cost) in neighbours(&curr) {
+            let next_cost = cost + edge_cost;
+            if closed.contains(&next) || best.get(&next).is_some_and(|&c| c <= next_cost) {
+                continue;
+            }
+            best.insert(next.clone(), next_cost);
+            let estimate = next_cost + heuristic(&next);
+            nodes.push((next, Some(curr.clone())));
+            heap.push(Reverse((estimate, next_cost, nodes.len() - 1)));
+        }
+    }
+
+    return None;
+}
+
+/// Shape of the sequence `start, f(start), f(f(start)), ...`: `tail` values lead into a
+/// repeating cycle of `length` values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub tail: usize,
+    pub length: usize,
+}
+
+/// Floyd's tortoise and hare cycle detection.
+pub fn floyd<N, F>(start: N, mut f: F) -> Cycle
+where
+    N: PartialEq + Clone,
+    F: FnMut(&N) -> N,
+{
+    let mut tortoise = f(&start);
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        hare = f(&hare);
+    }
+
+    let mut tail = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        tail += 1;
+    }
+
+    let mut length = 1;
+    hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        length += 1;
+    }
+
+    return Cycle { tail, length };
+}
+
+/// Brent's cycle detection, which calls `f` fewer times than `floyd`.
+pub fn brent<N, F>(start: N, mut f: F) -> Cycle
+where
+    N: PartialEq + Clone,
+    F: FnMut(&N) -> N,
+{
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = f(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = f(&hare);
+        length += 1;
+    }
+
+    let mut tail = 0;
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..length {
+        hare = f(&hare);
+    }
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        tail += 1;
+    }
+
+    return Cycle { tail, length };
+}
+
+/// Groups `nodes` into connected components. `neighbours` is expected to be symmetric;
+/// nodes it yields that aren't in `nodes` are still included in their component.
+pub fn connected_components<N, F, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: F,
+) -> Vec<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = vec![];
+    let mut visited = HashSet::new();
+    for node in nodes {
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+        let mut component = vec![node];
+        let mut i = 0;
+        while i < component.len() {
+            for next in neighbours(&component[i]) {
+                if visited.insert(next.clone()) {
+                    component.push(next);
+                }
+            }
+            i += 1;
+        }
+        result.push(component);
+    }
+
+    return result;
+}
+
+/// Orders `nodes` so every node comes before the nodes `neighbours` says it points to.
+/// Returns an error if the graph contains a cycle.
+pub fn topological_sort<N, F, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: F,
+) -> Result<Vec<N>, String>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let nodes = nodes.into_iter().collect::<Vec<N>>();
+    let mut edges: HashMap<N, Vec<N>> = HashMap::new();
+    let mut in_degree: HashMap<N, usize> = HashMap::new();
+    let mut order = vec![];
+
+    for node in nodes.iter() {
+        in_degree.entry(node.clone()).or_insert(0);
+    }
+    let mut i = 0;
+    let mut pending = nodes;
+    while i < pending.len() {
+        let node = pending[i].clone();
+        i += 1;
+        if edges.contains_key(&node) {
+            continue;
+        }
+        order.push(node.clone());
+        let next_nodes = neighbours(&node).into_iter().collect::<Vec<N>>();
+        for next in next_nodes.iter() {
+            *in_degree.entry(next.clone()).or_insert(0) += 1;
+            if !edges.contains_key(next) {
+                pending.push(next.clone());
+            }
+        }
+        edges.insert(node, next_nodes);
+    }
+
+    let mut queue = order
+        .iter()
+        .filter(|n| in_degree[*n] == 0)
+        .cloned()
+        .collect::<VecDeque<N>>();
+    let mut result = vec![];
+    while let Some(curr) = queue.pop_front() {
+        for next in edges[&curr].iter() {
+            let degree = in_degree.get_mut(next).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                queue.push_back(next.clone());
+            }
+        }
+        result.push(curr);
+    }
+
+    if result.len() != order.len() {
+        return Err(format!(
+            "Graph contains a cycle; only {} of {} nodes could be ordered.",
+            result.len(),
+            order.len()
+        ));
+    }
+
+    return Ok(result);
+}
+
+/// In-bound neighbours of `(x, y)` on a `width` by `height` grid, orthogonal first and
+/// then, if `diagonals` is set, the four corners.
+pub fn grid_neighbours(
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    diagonals: bool,
+) -> Vec<(usize, usize)> {
+    const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (1, 0), (0, 1)];
+    const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (1, -1), (-1, 1), (1, 1)];
+
+    let offsets = match diagonals {
+        true => ORTHOGONAL.iter().chain(DIAGONAL.iter()).collect::<Vec<_>>(),
+        false => ORTHOGONAL.iter().collect::<Vec<_>>(),
+    };
+
+    let mut result = vec![];
+    for (x_off, y_off) in offsets {
+        if let (Some(nx), Some(ny)) = (x.checked_add_signed(*x_off), y.checked_add_signed(*y_off)) {
+            if nx < width && ny < height {
+                result.push((nx, ny));
+            }
+        }
+    }
+    return result;
+}
+
+fn unwind_path<N>(parents: &HashMap<N, Option<N>>, end: N) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+{
+    let mut result = vec![end];
+    while let Some(Some(parent)) = parents.get(&result[result.len() - 1]) {
+        result.push(parent.clone());
+    }
+    result.reverse();
+    return result;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::*;
+
+    fn line_neighbours(n: &i32) -> Vec<i32> {
+        return vec![n - 1, n + 1]
+            .into_iter()
+            .filter(|n| (0..10).contains(n))
+            .collect();
+    }
+
+    fn weighted_neighbours(n: &char) -> Vec<(char, u64)> {
+        // a -1-> b -1-> c -1-> d, and a shortcut a -5-> d.
+        return match n {
+            'a' => vec![('b', 1), ('d', 5)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        };
+    }
+
+    #[test]
+    fn test_bfs() {
+        let distances = bfs(3, line_neighbours);
+        assert_eq!(distances.len(), 10);
+        assert_eq!(distances[&0], 3);
+        assert_eq!(distances[&9], 6);
+        assert_eq!(
+            bfs_path(3, line_neighbours, |n| *n == 5),
+            Some(vec![3, 4, 5])
+        );
+        assert_eq!(bfs_path(3, line_neighbours, |n| *n == 50), None);
+    }
+
+    #[test]
+    fn test_dfs() {
+        assert_eq!(dfs(0, line_neighbours), (0..10).collect::<Vec<i32>>());
+        assert_eq!(
+            dfs('a', |n| weighted_neighbours(n).into_iter().map(|(n, _)| n)),
+            vec!['a', 'b', 'c', 'd']
+        );
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        let costs = dijkstra('a', weighted_neighbours);
+        assert_eq!(costs[&'d'], 3);
+        assert_eq!(
+            dijkstra_path('a', weighted_neighbours, |n| *n == 'd'),
+            Some((vec!['a', 'b', 'c', 'd'], 3))
+        );
+
+        let grid_path = astar(
+            (0usize, 0usize),
+            |&(x, y)| {
+                grid_neighbours(x, y, 5, 5, false)
+                    .into_iter()
+                    .map(|n| (n, 1))
+            },
+            |&(x, y)| ((4 - x) + (4 - y)) as u64,
+            |n| *n == (4, 4),
+        );
+        let (path, cost) = grid_path.unwrap();
+        assert_eq!(cost, 8);
+        assert_eq!(path.len(), 9);
+    }
+
+    #[test]
+    fn test_cycle_detection() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2 ...
+        let f = |n: &u32| if *n < 4 { n + 1 } else { 2 };
+        let expected = Cycle { tail: 2, length: 3 };
+        assert_eq!(floyd(0, f), expected);
+        assert_eq!(brent(0, f), expected);
+        assert_eq!(brent(3, f), Cycle { tail: 0, length: 3 });
+    }
+
+    #[test]
+    fn test_connected_components_and_topological_sort() {
+        let components = connected_components(0..6, |n: &i32| match n {
+            0 => vec![1],
+            1 => vec![0],
+            3 => vec![4, 5],
+            4 | 5 => vec![3],
+            _ => vec![],
+        });
+        assert_eq!(components, vec![vec![0, 1], vec![2], vec![3, 4, 5]]);
+
+        let order = topological_sort(vec!['a'], |n| {
+            weighted_neighbours(n).into_iter().map(|(n, _)| n)
+        });
+        assert_eq!(order, Ok(vec!['a', 'b', 'c', 'd']));
+        assert!(topological_sort(vec![0, 1], |n: &i32| vec![1 - n]).is_err());
+    }
+
+    #[test]
+    fn test_grid_neighbours() {
+        assert_eq!(grid_neighbours(0, 0, 3, 3, false), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid_neighbours(1, 1, 3, 3, true).len(), 8);
+    }
+}
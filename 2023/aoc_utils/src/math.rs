@@ -0,0 +1,654 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let (mut a, mut b) = (a, b);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    return a;
+}
+
+fn gcd_u128(a: u128, b: u128) -> u128 {
+    let (mut a, mut b) = (a, b);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    return a;
+}
+
+/// Least common multiple of `a` and `b`, or `None` if it doesn't fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    return (a / gcd(a, b)).checked_mul(b);
+}
+
+pub fn gcd_slice(nums: &[u64]) -> u64 {
+    return nums.iter().fold(0, |acc, &n| gcd(acc, n));
+}
+
+/// Least common multiple of every number in `nums`, or `None` if it overflows a `u64`.
+/// An empty slice has a least common multiple of 1.
+pub fn lcm_slice(nums: &[u64]) -> Option<u64> {
+    let mut result = 1;
+    for &n in nums.iter() {
+        result = lcm(result, n)?;
+    }
+    return Some(result);
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        return (-old_r, -old_x, -old_y);
+    }
+    return (old_r, old_x, old_y);
+}
+
+/// Solves the system `x = residue (mod modulus)` for every `(residue, modulus)` pair, where
+/// the moduli don't need to be coprime. Returns the smallest non-negative solution along
+/// with the combined modulus, or `None` if the congruences conflict or the combined
+/// modulus overflows.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    let mut result: i128 = 0;
+    let mut modulus: i128 = 1;
+    for &(residue, m) in congruences.iter() {
+        if m <= 0 {
+            return None;
+        }
+        let residue = residue.rem_euclid(m);
+        let (g, p, _) = extended_gcd(modulus, m);
+        let diff = residue - result;
+        if diff % g != 0 {
+            return None;
+        }
+        let step = m / g;
+        let k = mul_mod_i128((diff / g).rem_euclid(step), p.rem_euclid(step), step);
+        let next_modulus = modulus.checked_mul(step)?;
+        result = (result + modulus.checked_mul(k)?).rem_euclid(next_modulus);
+        modulus = next_modulus;
+    }
+    return Some((result, modulus));
+}
+
+/// Largest integer whose square is no greater than `n`.
+pub fn isqrt(n: u128) -> u128 {
+    return n.isqrt();
+}
+
+pub fn is_perfect_square(n: u128) -> bool {
+    let root = isqrt(n);
+    return root * root == n;
+}
+
+/// `(a * b) % m` without overflowing, by widening to `u128`.
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    return ((a as u128 * b as u128) % m as u128) as u64;
+}
+
+/// `(a * b) % m` for any positive `m`, using doubling. Each sum is taken as a distance
+/// below `m` rather than added outright, so nothing overflows even when `m` is close to
+/// `i128::MAX`. Panics if `m` isn't positive.
+pub fn mul_mod_i128(a: i128, b: i128, m: i128) -> i128 {
+    assert!(m > 0, "mul_mod_i128 needs a positive modulus, not {}", m);
+    // Both operands are already reduced below `m`.
+    let add_mod = |x: i128, y: i128| match x >= m - y {
+        true => x - (m - y),
+        false => x + y,
+    };
+
+    let (mut a, mut b) = (a.rem_euclid(m), b.rem_euclid(m));
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a);
+        }
+        a = add_mod(a, a);
+        b >>= 1;
+    }
+    return result;
+}
+
+/// Narrows a `u128` to a `u64`, or `None` if it doesn't fit.
+pub fn to_u64(n: u128) -> Option<u64> {
+    return u64::try_from(n).ok();
+}
+
+/// Narrows an `i128` to an `i64`, or `None` if it doesn't fit.
+pub fn to_i64(n: i128) -> Option<i64> {
+    return i64::try_from(n).ok();
+}
+
+/// An exact fraction, always stored in lowest terms with a positive denominator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+impl Rational {
+    /// Reduces `num / den` to lowest terms, working with the magnitudes as `u128` so that
+    /// `i128::MIN` can be reduced too. Fails if the reduced fraction doesn't fit, which is
+    /// only when its numerator or denominator would be 2^127.
+    pub fn new(num: i128, den: i128) -> Result<Self, String> {
+        if den == 0 {
+            return Err(format!("Rational {}/{} has a zero denominator.", num, den));
+        }
+        let overflow = || format!("Rational {}/{} doesn't fit in lowest terms.", num, den);
+
+        let g = gcd_u128(num.unsigned_abs(), den.unsigned_abs());
+        let (num_magnitude, den_magnitude) = (num.unsigned_abs() / g, den.unsigned_abs() / g);
+        let den_reduced = i128::try_from(den_magnitude).map_err(|_| overflow())?;
+        let num_reduced = match (num < 0) != (den < 0) {
+            true => 0i128.checked_sub_unsigned(num_magnitude),
+            false => i128::try_from(num_magnitude).ok(),
+        };
+        return Ok(Self {
+            num: num_reduced.ok_or_else(overflow)?,
+            den: den_reduced,
+        });
+    }
+
+    pub fn from_int(n: i128) -> Self {
+        return Self { num: n, den: 1 };
+    }
+
+    pub fn zero() -> Self {
+        return Self::from_int(0);
+    }
+
+    pub fn one() -> Self {
+        return Self::from_int(1);
+    }
+
+    pub fn num(&self) -> i128 {
+        return self.num;
+    }
+
+    pub fn den(&self) -> i128 {
+        return self.den;
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.num == 0;
+    }
+
+    pub fn is_integer(&self) -> bool {
+        return self.den == 1;
+    }
+
+    /// The value as an integer, or `None` if it has a fractional part.
+    pub fn to_integer(&self) -> Option<i128> {
+        return match self.is_integer() {
+            true => Some(self.num),
+            false => None,
+        };
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let g = gcd_u128(self.den as u128, other.den as u128) as i128;
+        let den = (self.den / g).checked_mul(other.den)?;
+        let num = self
+            .num
+            .checked_mul(other.den / g)?
+            .checked_add(other.num.checked_mul(self.den / g)?)?;
+        return Self::new(num, den).ok();
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        return self.checked_add(&other.checked_neg()?);
+    }
+
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        // Cross-reduce first so the products stay as small as possible.
+        // Denominators are positive, so each gcd is no bigger than one and fits an i128.
+        let g1 = gcd_u128(self.num.unsigned_abs(), other.den as u128) as i128;
+        let g2 = gcd_u128(other.num.unsigned_abs(), self.den as u128) as i128;
+        let num = (self.num / g1).checked_mul(other.num / g2)?;
+        let den = (self.den / g2).checked_mul(other.den / g1)?;
+        return Self::new(num, den).ok();
+    }
+
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        return self.checked_mul(&other.recip()?);
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        return Some(Self {
+            num: self.num.checked_neg()?,
+            den: self.den,
+        });
+    }
+
+    /// `1 / self`, or `None` if `self` is zero.
+    pub fn recip(&self) -> Option<Self> {
+        return Self::new(self.den, self.num).ok();
+    }
+}
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        return Self::from_int(n as i128);
+    }
+}
+impl Add for Rational {
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        return self
+            .checked_add(&other)
+            .expect("Rational addition overflowed");
+    }
+}
+impl Sub for Rational {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        return self
+            .checked_sub(&other)
+            .expect("Rational subtraction overflowed");
+    }
+}
+impl Mul for Rational {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        return self
+            .checked_mul(&other)
+            .expect("Rational multiplication overflowed");
+    }
+}
+impl Div for Rational {
+    type Output = Self;
+    fn div(self, other: Self) -> Self::Output {
+        return self
+            .checked_div(&other)
+            .expect("Rational division by zero or overflow");
+    }
+}
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        return self.checked_neg().expect("Rational negation overflowed");
+    }
+}
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are always positive, so cross-multiplying keeps the ordering. If
+        // either product overflows, they're compared as big integers instead.
+        let left = self.num.checked_mul(other.den);
+        let right = other.num.checked_mul(self.den);
+        if let (Some(left), Some(right)) = (left, right) {
+            return left.cmp(&right);
+        }
+        let left = BigInt::from(self.num) * BigInt::from(other.den);
+        let right = BigInt::from(other.num) * BigInt::from(self.den);
+        return left.cmp(&right);
+    }
+}
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.is_integer() {
+            true => write!(f, "{}", self.num),
+            false => write!(f, "{}/{}", self.num, self.den),
+        }
+    }
+}
+
+/// A whole number of any size, stored as its sign and its base 2^32 digits, least
+/// significant first, with no leading zero digits. Zero has no digits and is never
+/// negative.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    digits: Vec<u32>,
+}
+impl BigInt {
+    pub fn zero() -> Self {
+        return Self::from_parts(false, vec![]);
+    }
+
+    pub fn from_int(n: i128) -> Self {
+        let mut magnitude = n.unsigned_abs();
+        let mut digits = vec![];
+        while magnitude > 0 {
+            digits.push(magnitude as u32);
+            magnitude >>= 32;
+        }
+        return Self::from_parts(n < 0, digits);
+    }
+
+    fn from_parts(negative: bool, mut digits: Vec<u32>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        return Self {
+            negative: negative && !digits.is_empty(),
+            digits,
+        };
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.digits.is_empty();
+    }
+
+    pub fn is_negative(&self) -> bool {
+        return self.negative;
+    }
+
+    /// The value as an `i128`, or `None` if it doesn't fit.
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.digits.len() > 4 {
+            return None;
+        }
+        let magnitude = self
+            .digits
+            .iter()
+            .rev()
+            .fold(0u128, |m, &d| (m << 32) | d as u128);
+        return match self.negative {
+            true if magnitude == i128::MIN.unsigned_abs() => Some(i128::MIN),
+            true => i128::try_from(magnitude).ok().map(|m| -m),
+            false => i128::try_from(magnitude).ok(),
+        };
+    }
+
+    /// The value as an `i64`, or `None` if it doesn't fit.
+    pub fn to_i64(&self) -> Option<i64> {
+        return self.to_i128().and_then(|n| i64::try_from(n).ok());
+    }
+
+    /// Divides by `divisor`, rounding towards zero, along with the size of the remainder.
+    /// `None` if `divisor` is zero.
+    pub fn div_rem(&self, divisor: u32) -> Option<(Self, u32)> {
+        if divisor == 0 {
+            return None;
+        }
+
+        let mut quotient = vec![0; self.digits.len()];
+        let mut remainder = 0u64;
+        for i in (0..self.digits.len()).rev() {
+            let current = (remainder << 32) | self.digits[i] as u64;
+            quotient[i] = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+        return Some((Self::from_parts(self.negative, quotient), remainder as u32));
+    }
+}
+impl From<i64> for BigInt {
+    fn from(n: i64) -> Self {
+        return Self::from_int(n as i128);
+    }
+}
+impl From<i128> for BigInt {
+    fn from(n: i128) -> Self {
+        return Self::from_int(n);
+    }
+}
+
+fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    return a
+        .len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()));
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0u64;
+    for i in 0..a.len().max(b.len()) {
+        let sum = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    result.push(carry as u32);
+    return result;
+}
+
+/// `a - b`, where `a` is at least as large as `b`.
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &digit) in a.iter().enumerate() {
+        let mut difference = digit as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = 0;
+        if difference < 0 {
+            difference += 1 << 32;
+            borrow = 1;
+        }
+        result.push(difference as u32);
+    }
+    return result;
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let product = x as u64 * y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    return result;
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+    fn add(self, other: Self) -> Self::Output {
+        if self.negative == other.negative {
+            return BigInt::from_parts(self.negative, add_magnitude(&self.digits, &other.digits));
+        }
+        return match cmp_magnitude(&self.digits, &other.digits) {
+            Ordering::Less => {
+                BigInt::from_parts(other.negative, sub_magnitude(&other.digits, &self.digits))
+            }
+            _ => BigInt::from_parts(self.negative, sub_magnitude(&self.digits, &other.digits)),
+        };
+    }
+}
+impl Add for BigInt {
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        return &self + &other;
+    }
+}
+impl Sub for &BigInt {
+    type Output = BigInt;
+    fn sub(self, other: Self) -> Self::Output {
+        return self + &-other;
+    }
+}
+impl Sub for BigInt {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        return &self - &other;
+    }
+}
+impl Mul for &BigInt {
+    type Output = BigInt;
+    fn mul(self, other: Self) -> Self::Output {
+        return BigInt::from_parts(
+            self.negative != other.negative,
+            mul_magnitude(&self.digits, &other.digits),
+        );
+    }
+}
+impl Mul for BigInt {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        return &self * &other;
+    }
+}
+impl Neg for &BigInt {
+    type Output = BigInt;
+    fn neg(self) -> Self::Output {
+        return BigInt::from_parts(!self.negative, self.digits.clone());
+    }
+}
+impl Neg for BigInt {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        return -&self;
+    }
+}
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitude(&self.digits, &other.digits),
+            (true, true) => cmp_magnitude(&other.digits, &self.digits),
+        };
+    }
+}
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+impl Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        // Peel off nine decimal digits at a time, least significant first.
+        let mut chunks = vec![];
+        let mut rest = BigInt::from_parts(false, self.digits.clone());
+        while !rest.is_zero() {
+            let (quotient, remainder) = rest.div_rem(1_000_000_000).unwrap();
+            chunks.push(remainder);
+            rest = quotient;
+        }
+
+        let mut result = match self.negative {
+            true => format!("-{}", chunks[chunks.len() - 1]),
+            false => format!("{}", chunks[chunks.len() - 1]),
+        };
+        for chunk in chunks.iter().rev().skip(1) {
+            result = format!("{}{:09}", result, chunk);
+        }
+        write!(f, "{}", result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd_slice(&[12, 18, 27]), 3);
+        assert_eq!(lcm_slice(&[4, 6, 10]), Some(60));
+        assert_eq!(lcm_slice(&[]), Some(1));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli sharing a factor still combine when they agree on it.
+        assert_eq!(crt(&[(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt(&[(0, 4), (1, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+
+        // Sums near the modulus would overflow if added outright.
+        let m = i128::MAX;
+        assert_eq!(mul_mod_i128(m - 1, m - 1, m), 1);
+        assert_eq!(mul_mod_i128(m - 1, 2, m), m - 2);
+        assert_eq!(mul_mod_i128(-1, 3, m), m - 3);
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+        assert!(is_perfect_square(144));
+        assert!(!is_perfect_square(145));
+        assert_eq!(mul_mod(u64::MAX, u64::MAX, 1_000_000_007), 114_944_269);
+        assert_eq!(to_u64(u64::MAX as u128 + 1), None);
+    }
+
+    #[test]
+    fn test_rational() {
+        let half = Rational::new(2, 4).unwrap();
+        let third = Rational::new(-1, -3).unwrap();
+        assert_eq!((half.num(), half.den()), (1, 2));
+        assert_eq!(half + third, Rational::new(5, 6).unwrap());
+        assert_eq!(half - third, Rational::new(1, 6).unwrap());
+        assert_eq!(half * third, Rational::new(1, 6).unwrap());
+        assert_eq!(half / third, Rational::new(3, 2).unwrap());
+        assert_eq!((-half).to_string(), "-1/2");
+        assert!(third < half);
+        assert_eq!((half + half).to_integer(), Some(1));
+        assert!(Rational::new(1, 0).is_err());
+        assert_eq!(Rational::zero().recip(), None);
+
+        // i128::MIN reduces, unless its magnitude would end up positive or on the bottom.
+        let min = Rational::new(i128::MIN, 1).unwrap();
+        assert_eq!((min.num(), min.den()), (i128::MIN, 1));
+        assert_eq!(Rational::new(i128::MIN, i128::MIN), Ok(Rational::one()));
+        assert_eq!(Rational::new(2, i128::MIN).unwrap().den(), 1 << 126);
+        assert!(Rational::new(i128::MIN, -1).is_err());
+        assert!(Rational::new(1, i128::MIN).is_err());
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(
+            min.checked_add(&Rational::one()).unwrap().num(),
+            i128::MIN + 1
+        );
+
+        // Comparisons whose cross products overflow.
+        let max = Rational::from_int(i128::MAX);
+        assert!(max > Rational::new(1, i128::MAX).unwrap());
+        assert!(Rational::new(i128::MAX, 2).unwrap() > Rational::new(i128::MAX - 2, 2).unwrap());
+        assert!(Rational::new(i128::MIN, 3).unwrap() < Rational::new(i128::MIN + 1, 3).unwrap());
+        assert!(min < max);
+    }
+
+    #[test]
+    fn test_big_int() {
+        let max = BigInt::from(i64::MAX);
+        let min = BigInt::from(i64::MIN);
+        assert_eq!((&max + &max).to_string(), "18446744073709551614");
+        assert_eq!((&min - &max).to_string(), "-18446744073709551615");
+        assert_eq!(
+            (&max * &min).to_string(),
+            "-85070591730234615856620279821087277056"
+        );
+        assert_eq!((&max + &min).to_i64(), Some(-1));
+        assert_eq!((&max + &BigInt::from(1i64)).to_i64(), None);
+        assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!(
+            (&BigInt::from(i128::MIN) - &BigInt::from(1i64)).to_i128(),
+            None
+        );
+        assert_eq!((&max - &max), BigInt::zero());
+        assert!(!(-BigInt::zero()).is_negative());
+        assert!(min < BigInt::zero() && BigInt::zero() < max);
+
+        let (quotient, remainder) = BigInt::from(-1_000_000_007i64).div_rem(10).unwrap();
+        assert_eq!((quotient.to_i64(), remainder), (Some(-100_000_000), 7));
+        assert_eq!(max.div_rem(0), None);
+        assert_eq!(BigInt::from(-1_000_000_000i64).to_string(), "-1000000000");
+    }
+}